@@ -8,7 +8,28 @@ pub enum DataType {
     },
     /// A flat enumeration, where each item is represented by a number
     /// (also called unit-only enum)
-    Enum { name: String, items: Vec<String> },
+    Enum {
+        name: String,
+        /// Names and values of the items
+        items: Vec<(String, String)>,
+    },
+    /// An enumeration of integers, where each item has an explicit
+    /// discriminant equal to its value, unless the enumeration gets the
+    /// `Unknown` variant
+    IntegerEnum {
+        name: String,
+        /// Names and values of the items
+        items: Vec<(String, i64)>,
+    },
+    /// An enumeration, where each item contains a value of another type
+    /// (`oneOf` or `anyOf` at the root of the schema)
+    Union {
+        name: String,
+        /// Name of the property containing the tag, if the enumeration is
+        /// tagged
+        tag: Option<String>,
+        variants: Vec<UnionVariant>,
+    },
     /// An extra name for existing type
     Alias {
         alias: String,
@@ -21,9 +42,11 @@ impl DataType {
     /// The name of the schema that the data type represents
     pub fn schema_name(&self) -> &str {
         match self {
-            DataType::Struct { name, .. } => &name,
-            DataType::Enum { name, .. } => &name,
-            DataType::Alias { alias, .. } => &alias,
+            DataType::Struct { name, .. } => name,
+            DataType::Enum { name, .. } => name,
+            DataType::IntegerEnum { name, .. } => name,
+            DataType::Union { name, .. } => name,
+            DataType::Alias { alias, .. } => alias,
         }
     }
 
@@ -31,15 +54,42 @@ impl DataType {
     pub fn sort_fields(&mut self) {
         match self {
             DataType::Struct { fields, .. } => {
-                fields.sort_by(|a, b| a.name.cmp(&b.name));
+                // the flattened fields are kept at the end
+                fields.sort_by(|a, b| (a.is_flatten, &a.name).cmp(&(b.is_flatten, &b.name)));
             }
-            DataType::Enum { items, .. } => items.sort(),
+            DataType::Enum { items, .. } => items.sort_by(|a, b| a.1.cmp(&b.1)),
+            DataType::IntegerEnum { items, .. } => items.sort_by_key(|item| item.1),
+            // the order of the untagged variants matters for deserialization
+            DataType::Union { .. } => (),
             DataType::Alias { .. } => (),
         }
     }
 }
 
+/// A variant of [`DataType::Union`]
+pub struct UnionVariant {
+    /// Variant name
+    pub name: String,
+    /// Value of the tag property, if the enumeration is tagged
+    pub tag_value: String,
+    /// Type of the variant value
+    pub info: StructField,
+}
+
+/// A variant of [`FieldType::Tagged`]
+#[derive(Clone, PartialEq)]
+pub struct TaggedVariant {
+    /// Variant name, the name of the schema
+    pub name: String,
+    /// Type of the variant value, a copy of the schema without the tag
+    /// property, if the schema has it
+    pub type_name: String,
+    /// Value of the tag property
+    pub tag_value: String,
+}
+
 /// Representation of schema object properties as structure fields
+#[derive(Clone)]
 pub struct StructField {
     /// Field name
     pub name: String,
@@ -58,15 +108,45 @@ pub struct StructField {
     pub array_dimensions: i32,
     /// Can be null or not
     pub is_nullable: bool,
+    /// Must be present in the object or not
+    pub is_required: bool,
+    /// The field is not a property, but contains properties of the object
+    /// itself (`#[serde(flatten)]`)
+    pub is_flatten: bool,
+    /// The value is stored in a [`Box`], because the field makes the type
+    /// recursive
+    pub is_boxed: bool,
     /// Comments
     pub descr: String,
 }
 
+#[derive(Clone)]
 pub enum FieldType {
     /// Just type name
     Plain(String),
     /// Type name can be one of these values
     OneOf(Vec<String>),
+    /// Type name is determined by the value of the property
+    Tagged {
+        /// Name of the property containing the value
+        property: String,
+        variants: Vec<TaggedVariant>,
+    },
+    /// A map from string keys to the values of the specified type
+    Map(Box<StructField>),
+}
+
+impl StructField {
+    /// The value may be absent or null, so it is wrapped into an [`Option`]
+    pub fn is_optional(&self) -> bool {
+        !self.is_required || self.is_nullable
+    }
+
+    /// The value may be both absent and null, which can be distinguished by
+    /// wrapping it into `Option<Option<T>>`
+    pub fn is_absent_or_null(&self) -> bool {
+        !self.is_required && self.is_nullable
+    }
 }
 
 impl FieldType {
@@ -75,6 +155,10 @@ impl FieldType {
         match self {
             FieldType::Plain(t) => vec![t.clone()],
             FieldType::OneOf(items) => items.clone(),
+            FieldType::Tagged { variants, .. } => {
+                variants.iter().map(|v| v.type_name.clone()).collect()
+            }
+            FieldType::Map(value) => value.type_.to_vec(),
         }
     }
 }
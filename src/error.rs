@@ -0,0 +1,177 @@
+use std::fmt;
+use std::path::PathBuf;
+
+/// Errors of reading the specification or the configuration and of
+/// generating the types. The locations in the specification are JSON
+/// pointers, like `#/components/schemas/Order/properties/status`.
+///
+/// New kinds of errors can be added in the future, so a `match` needs a
+/// wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A file cannot be read or written
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The document is not a valid JSON or YAML, or its structure is not
+    /// the expected one. The path is absent, if the document was read from a
+    /// string.
+    Parse {
+        path: Option<PathBuf>,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+    /// The file extension is neither `json`, nor `yaml` (`yml`)
+    UnknownFileFormat { path: PathBuf },
+    /// A reference to a schema that cannot be found
+    UnresolvedReference { location: String, reference: String },
+    /// The construct of the specification cannot be represented by the
+    /// generated types, like `allOf` of a string and an object
+    Unsupported { location: String, message: String },
+    /// The generated types refer to the schema that is not generated (for
+    /// example, excluded by the filter)
+    MissingSchema { location: String, name: String },
+    /// The property name cannot be turned into a Rust name
+    UntranslatableName { location: String },
+    /// The regular expression in the filter is invalid, or the `regex`
+    /// feature is not enabled
+    InvalidPattern { pattern: String, message: String },
+    /// The operation listed in the filter is not found in the specification,
+    /// neither by `operationId`, nor by the method and the path
+    UnknownOperation { operation: String },
+    /// The entry of `include` or `exclude` does not match the specification.
+    /// It is an error only in the strict mode, otherwise it is a warning. The
+    /// location is a JSON pointer in the filter, like `#/include/Pet`.
+    InvalidFilter { location: String, message: String },
+    /// The generated code cannot be written
+    Fmt(fmt::Error),
+    /// Several problems found during the generation, none of them is
+    /// [`Error::Multiple`]
+    Multiple(Vec<Error>),
+    /// The generation failed, and some warnings were found before that.
+    /// Returned only by
+    /// [`generate_openapi_types_with_warnings`](crate::generate_openapi_types_with_warnings),
+    /// the error is never [`Error::WithWarnings`] itself.
+    WithWarnings {
+        error: Box<Error>,
+        warnings: Vec<String>,
+    },
+}
+
+impl Error {
+    /// Creates [`Error::Unsupported`] for the construct at `location`
+    pub(crate) fn unsupported(location: &str, message: impl Into<String>) -> Self {
+        Error::Unsupported {
+            location: location.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the only error as is, and several errors as
+    /// [`Error::Multiple`]
+    pub(crate) fn from_list(mut errors: Vec<Error>) -> Result<(), Error> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Error::Multiple(errors)),
+        }
+    }
+
+    /// Iterates over the problems, [`Error::Multiple`] is expanded into the
+    /// errors it contains, and [`Error::WithWarnings`] into its error
+    pub fn iter(&self) -> impl Iterator<Item = &Error> {
+        let error = match self {
+            Error::WithWarnings { error, .. } => error,
+            _ => self,
+        };
+        match error {
+            Error::Multiple(errors) => errors.iter(),
+            _ => std::slice::from_ref(error).iter(),
+        }
+    }
+
+    /// Returns the warnings found before the generation failed, see
+    /// [`Error::WithWarnings`]
+    pub fn warnings(&self) -> &[String] {
+        match self {
+            Error::WithWarnings { warnings, .. } => warnings,
+            _ => &[],
+        }
+    }
+
+    /// Drops the warnings, returning the error itself
+    pub(crate) fn without_warnings(self) -> Error {
+        match self {
+            Error::WithWarnings { error, .. } => *error,
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Parse {
+                path: Some(path),
+                source,
+            } => write!(f, "{}: {source}", path.display()),
+            Error::Parse { path: None, source } => write!(f, "{source}"),
+            Error::UnknownFileFormat { path } => {
+                write!(f, "{}: couldn't determine the file format", path.display())
+            }
+            Error::UnresolvedReference {
+                location,
+                reference,
+            } => write!(f, "{location}: unresolved reference {reference:?}"),
+            Error::Unsupported { location, message } => write!(f, "{location}: {message}"),
+            Error::MissingSchema { location, name } => {
+                write!(f, "{location}: reference to missing schema {name:?}")
+            }
+            Error::UntranslatableName { location } => {
+                write!(f, "{location}: the name cannot be translated into Rust")
+            }
+            Error::InvalidPattern { pattern, message } => {
+                write!(f, "invalid pattern {pattern:?}: {message}")
+            }
+            Error::UnknownOperation { operation } => write!(f, "unknown operation {operation:?}"),
+            Error::InvalidFilter { location, message } => write!(f, "{location}: {message}"),
+            Error::Fmt(source) => write!(f, "{source}"),
+            Error::Multiple(errors) => {
+                let errors: Vec<_> = errors.iter().map(|e| e.to_string()).collect();
+                write!(f, "{}", errors.join("\n"))
+            }
+            // the warnings are not the cause of the error
+            Error::WithWarnings { error, .. } => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source.as_ref()),
+            Error::Fmt(source) => Some(source),
+            Error::WithWarnings { error, .. } => error.source(),
+            _ => None,
+        }
+    }
+}
+
+/// Reports a problem that does not prevent the generation. In a build script
+/// (where `OUT_DIR` is set), it is shown by Cargo.
+pub(crate) fn warn(message: &str) {
+    if std::env::var_os("OUT_DIR").is_some() {
+        println!("cargo:warning={message}");
+    } else {
+        eprintln!("warning: {message}");
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(value: fmt::Error) -> Self {
+        Error::Fmt(value)
+    }
+}
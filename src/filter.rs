@@ -1,5 +1,10 @@
+use crate::datatypes::DataType;
+use crate::error::Error;
+use crate::openapi::OpenApi;
+use crate::processing;
+use crate::resolving;
 use serde::Deserialize;
-use std::{collections::HashMap, fs::File, path::Path};
+use std::{collections::HashMap, path::Path};
 
 /// Configuration for the generator
 ///
@@ -26,12 +31,25 @@ use std::{collections::HashMap, fs::File, path::Path};
 ///    - Debug
 ///    - Deserialize
 /// </pre>
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 pub struct FilterConfig {
     /// Names of schemes to include in the generated file
     pub include: Option<HashMap<String, SchemaFilter>>,
     /// Names of schemes to exclude from the generated file
     pub exclude: Option<HashMap<String, SchemaFilter>>,
+    /// API operations, by `operationId` or by the method and the path like
+    /// `"GET /pets/{id}"`. The schemas used by their parameters, request
+    /// bodies and responses are included with all their dependencies.
+    #[serde(default)]
+    pub include_operations: Vec<String>,
+    /// Tags of the API operations, the schemas used by the operations with
+    /// any of these tags are included like with `include_operations`
+    #[serde(default)]
+    pub include_tags: Vec<String>,
+    /// Tags of the API operations that are not selected by `include_tags`.
+    /// If `include_tags` is empty, all the other operations are selected.
+    #[serde(default)]
+    pub exclude_tags: Vec<String>,
     /// Defines a list of `#[derive(...)]` when generating the structure. By
     /// default, `#[derive(Debug, Clone, serde::Deserialize)]`.
     #[serde(default = "get_default_struct_derives")]
@@ -45,6 +63,55 @@ pub struct FilterConfig {
     /// schema refer to it
     #[serde(default)]
     pub auto_include_dependencies: bool,
+    /// Names of the types generated for nested objects and enums, by the path
+    /// like `"Order.shippingAddress"`. By default, the name is made up of
+    /// the schema name and the property name: `OrderShippingAddress`.
+    #[serde(default)]
+    pub inline_type_names: HashMap<String, String>,
+    /// If `true`, `anyOf` of objects is represented as a structure with an
+    /// optional flattened field for each member, instead of an untagged enum
+    #[serde(default)]
+    pub any_of_as_struct: bool,
+    /// Names of the variants of the enumerations generated for `oneOf` and
+    /// `anyOf` schemas, by the path like `"Pet.Cat"`. By default, the
+    /// variant is named after the type of its value.
+    #[serde(default)]
+    pub union_variant_names: HashMap<String, String>,
+    /// If `true`, the generated types also implement `serde::Serialize`, and
+    /// the optional fields are omitted instead of being serialized as `null`.
+    /// This mode is also enabled if `Serialize` is listed in the derives.
+    #[serde(default)]
+    pub serialize: bool,
+    /// If `true`, the properties that are both optional and nullable are
+    /// represented as `Option<Option<T>>`: `None` if the property is absent,
+    /// `Some(None)` if it is `null`. Requires the `serde_with` crate.
+    #[serde(default)]
+    pub double_option: bool,
+    /// Names of the enumerations that get the `Unknown` variant for the values
+    /// that are not listed in the specification, or `"*"` for all the
+    /// enumerations. Without it, a new value added by the server is a
+    /// deserialization error.
+    #[serde(default)]
+    pub unknown_enum_variants: Vec<String>,
+    /// Rust types for the OpenAPI types and formats, like `uuid::Uuid` for
+    /// `{"type": "string", "format": "uuid"}`. They take precedence over the
+    /// built-in types.
+    #[serde(default)]
+    pub type_mappings: Vec<TypeMapping>,
+    /// The library for the `date`, `date-time`, `time` and `duration`
+    /// formats. By default, `time`. Only `jiff` has a type for `duration`,
+    /// with the other libraries it is a [`String`].
+    #[serde(default)]
+    pub date_time_backend: DateTimeBackend,
+    /// If `true`, the other standard formats get more specific types, like
+    /// `uuid::Uuid` for `uuid` or `u64` for `uint64`. Some of them require
+    /// additional crates.
+    #[serde(default)]
+    pub standard_formats: bool,
+    /// If `true`, the entries of `include` and `exclude` that do not match
+    /// the specification are errors, otherwise they are reported as warnings
+    #[serde(default)]
+    pub strict: bool,
 }
 
 impl std::default::Default for FilterConfig {
@@ -54,7 +121,20 @@ impl std::default::Default for FilterConfig {
             enum_derives: get_default_enum_derives(),
             include: Default::default(),
             exclude: Default::default(),
+            include_operations: Default::default(),
+            include_tags: Default::default(),
+            exclude_tags: Default::default(),
             auto_include_dependencies: Default::default(),
+            inline_type_names: Default::default(),
+            any_of_as_struct: Default::default(),
+            union_variant_names: Default::default(),
+            serialize: Default::default(),
+            double_option: Default::default(),
+            unknown_enum_variants: Default::default(),
+            type_mappings: Default::default(),
+            date_time_backend: Default::default(),
+            standard_formats: Default::default(),
+            strict: Default::default(),
         }
     }
 }
@@ -76,12 +156,176 @@ fn get_default_enum_derives() -> Vec<String> {
     ]
 }
 
+/// The built-in types of the string formats. The expected values are in
+/// RFC 3339: "2017-07-21" for `date`, "2017-07-21T17:32:28Z" for
+/// `date-time`, "17:32:28" (without an offset, as the Rust types have no
+/// place for it) for `time`, and "P3DT4H" (ISO 8601) for `duration`.
+fn get_default_type_mappings(backend: DateTimeBackend) -> Vec<TypeMapping> {
+    let mapping = |format, rust_type, with, option_with| {
+        TypeMapping::builtin("string", format, rust_type, with, option_with)
+    };
+    match backend {
+        // the ISO 8601 durations are kept as strings: `time::Duration` cannot
+        // keep the months and years of "P1M", whose length depends on the
+        // date, and neither can `chrono::TimeDelta`
+        DateTimeBackend::Time => vec![
+            // the module is generated, `time` has no well-known format for
+            // the dates alone
+            mapping("date", "time::Date", Some(DATE_SERDE_MODULE), None),
+            mapping(
+                "date-time",
+                "time::OffsetDateTime",
+                Some("time::serde::rfc3339"),
+                Some("time::serde::rfc3339::option"),
+            ),
+            // the module is generated, `time` reads only "17:32:28.0" by
+            // itself
+            mapping("time", "time::Time", Some(TIME_SERDE_MODULE), None),
+        ],
+        // the same for `chrono`, the other types are read by themselves
+        DateTimeBackend::Chrono => vec![
+            mapping("date", "chrono::NaiveDate", None, None),
+            mapping(
+                "date-time",
+                "chrono::DateTime<chrono::FixedOffset>",
+                None,
+                None,
+            ),
+            mapping("time", "chrono::NaiveTime", None, None),
+        ],
+        DateTimeBackend::Jiff => vec![
+            mapping("date", "jiff::civil::Date", None, None),
+            mapping("date-time", "jiff::Timestamp", None, None),
+            mapping("time", "jiff::civil::Time", None, None),
+            mapping("duration", "jiff::Span", None, None),
+        ],
+    }
+}
+
+/// The types of the other standard formats, they are used only if
+/// `standard_formats` is enabled, since most of them require additional
+/// crates. The formats without a suitable type, like `email`, are left as
+/// strings, and so is `binary`, which describes the raw content rather than
+/// a JSON value.
+fn get_standard_format_mappings() -> Vec<TypeMapping> {
+    let mapping =
+        |type_, format, rust_type| TypeMapping::builtin(type_, format, rust_type, None, None);
+    vec![
+        mapping("integer", "int8", "i8"),
+        mapping("integer", "int16", "i16"),
+        mapping("integer", "uint8", "u8"),
+        mapping("integer", "uint16", "u16"),
+        mapping("integer", "uint32", "u32"),
+        mapping("integer", "uint64", "u64"),
+        // as a string or as a number
+        mapping("number", "decimal", "rust_decimal::Decimal"),
+        mapping("string", "decimal", "rust_decimal::Decimal"),
+        mapping("string", "uuid", "uuid::Uuid"),
+        mapping("string", "uri", "url::Url"),
+        mapping("string", "url", "url::Url"),
+        mapping("string", "ipv4", "std::net::Ipv4Addr"),
+        mapping("string", "ipv6", "std::net::Ipv6Addr"),
+        TypeMapping::builtin(
+            "string",
+            "byte",
+            "Vec<u8>",
+            Some("serde_with::As::<serde_with::base64::Base64>"),
+            Some("serde_with::As::<Option<serde_with::base64::Base64>>"),
+        ),
+    ]
+}
+
+/// Name of the generated module for (de)serialization of `time::Time`
+pub(crate) const TIME_SERDE_MODULE: &str = "_time_serde_time";
+
+/// Name of the generated module for (de)serialization of `time::Date`
+pub(crate) const DATE_SERDE_MODULE: &str = "_time_serde_date";
+
+/// The library, which types are used for dates and times
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum DateTimeBackend {
+    /// [time](https://crates.io/crates/time)
+    #[default]
+    Time,
+    /// [chrono](https://crates.io/crates/chrono)
+    Chrono,
+    /// [jiff](https://crates.io/crates/jiff)
+    Jiff,
+}
+
+/// Rust type for the values of the OpenAPI type and format. Only the
+/// specified conditions are checked, so the mapping can be applied to all
+/// the strings, to a format, or to a single field.
+#[derive(Deserialize, Clone)]
+pub struct TypeMapping {
+    /// OpenAPI type, like `"string"`
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    /// OpenAPI format, like `"uuid"`
+    pub format: Option<String>,
+    /// Name of the schema, whose fields are mapped
+    pub schema: Option<String>,
+    /// Name of the property
+    pub field: Option<String>,
+    /// Path of the Rust type, like `"uuid::Uuid"`
+    pub rust_type: String,
+    /// Module for `#[serde(with = "...")]`, if the type does not implement
+    /// serde traits in the required way
+    pub with: Option<String>,
+    /// The same as `with`, but for the optional fields (`Option<T>`)
+    pub option_with: Option<String>,
+}
+
+impl TypeMapping {
+    /// Creates a built-in mapping of the type and format
+    fn builtin(
+        type_: &str,
+        format: &str,
+        rust_type: &str,
+        with: Option<&str>,
+        option_with: Option<&str>,
+    ) -> Self {
+        Self {
+            type_: Some(type_.into()),
+            format: Some(format.into()),
+            schema: None,
+            field: None,
+            rust_type: rust_type.into(),
+            with: with.map(str::to_owned),
+            option_with: option_with.map(str::to_owned),
+        }
+    }
+
+    /// Returns `true` if all the specified conditions are met
+    fn is_matched(&self, schema_name: &str, field_name: &str, type_: &str, format: &str) -> bool {
+        let is_equal = |condition: &Option<String>, value: &str| match condition {
+            Some(condition) => condition == value,
+            None => true,
+        };
+        is_equal(&self.type_, type_)
+            && is_equal(&self.format, format)
+            && is_equal(&self.schema, schema_name)
+            && is_equal(&self.field, field_name)
+    }
+
+    /// The more specific mappings take precedence: the field, then the
+    /// schema, then the format, then the type
+    fn precedence(&self) -> (bool, bool, bool, bool) {
+        (
+            self.field.is_some(),
+            self.schema.is_some(),
+            self.format.is_some(),
+            self.type_.is_some(),
+        )
+    }
+}
+
 /// Filter element: either "*" or an array of strings
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 #[serde(untagged)]
 pub enum SchemaFilter {
     /// All properties are selected, i.e. `*`
-    #[allow(unused)]
     AcceptAll(String),
     /// Only some properties are selected
     AcceptSelected(Vec<String>),
@@ -91,86 +335,460 @@ impl SchemaFilter {
     pub(super) fn is_accepted(&self, name: &str) -> bool {
         match self {
             SchemaFilter::AcceptAll(_) => true,
-            SchemaFilter::AcceptSelected(items) => items.iter().any(|i| i == name),
+            SchemaFilter::AcceptSelected(items) => {
+                items.iter().any(|i| Pattern::parse(i).is_matched(name))
+            }
         }
     }
 }
 
-impl FilterConfig {
-    /// Read configuration from string
-    pub fn from_str(data: &str) -> Self {
-        if data.trim_start().starts_with("{") {
-            serde_json::from_str(data).unwrap()
+/// Name of a schema or a property in the filter: the exact name, a glob
+/// like `Billing*` (`*` is any number of characters, `?` is one character)
+/// or a regular expression like `/^Acme.+Dto$/`
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum Pattern<'a> {
+    Regex(&'a str),
+    Glob(&'a str),
+    Exact(&'a str),
+}
+
+impl<'a> Pattern<'a> {
+    fn parse(pattern: &'a str) -> Self {
+        if let Some(regex) = pattern.strip_prefix('/').and_then(|p| p.strip_suffix('/')) {
+            Pattern::Regex(regex)
+        } else if pattern.contains(['*', '?']) {
+            Pattern::Glob(pattern)
         } else {
-            serde_yaml::from_str(data).unwrap()
+            Pattern::Exact(pattern)
+        }
+    }
+
+    fn is_matched(&self, name: &str) -> bool {
+        match self {
+            Pattern::Exact(pattern) => *pattern == name,
+            Pattern::Glob(pattern) => is_glob_matched(pattern, name),
+            Pattern::Regex(pattern) => is_regex_matched(pattern, name),
+        }
+    }
+
+    /// Returns the problem of the regular expression, if it is invalid or
+    /// not supported
+    fn error(&self) -> Option<String> {
+        match self {
+            #[cfg(feature = "regex")]
+            Pattern::Regex(regex) => regex::Regex::new(regex).err().map(|e| e.to_string()),
+            #[cfg(not(feature = "regex"))]
+            Pattern::Regex(_) => Some("the `regex` feature is required".to_owned()),
+            Pattern::Glob(_) | Pattern::Exact(_) => None,
         }
     }
 
-    /// Read specifiaction from file
-    pub fn from_file<P: AsRef<Path>>(path: P) -> Self {
-        let path_ref = path.as_ref();
-        if path_ref.extension().unwrap() == "json" {
-            serde_json::from_reader(File::open(path_ref).unwrap()).unwrap()
-        } else if path_ref.extension().unwrap() == "yaml" {
-            serde_yaml::from_reader(File::open(path_ref).unwrap()).unwrap()
+    /// The exact names take precedence over the globs, and the globs over
+    /// the regular expressions. Among the patterns of the same kind, the
+    /// longer one is more specific.
+    fn precedence(&self) -> (u8, usize) {
+        match self {
+            Pattern::Exact(pattern) => (2, pattern.len()),
+            Pattern::Glob(pattern) => (1, pattern.len()),
+            Pattern::Regex(pattern) => (0, pattern.len()),
+        }
+    }
+}
+
+/// Matches the whole name against the glob with `*` and `?`
+fn is_glob_matched(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // the position of the last `*` and of the name when it was reached
+    let mut star = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            // let the `*` take one more character
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, n));
         } else {
-            panic!("Couldn't determine the config file format");
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Searches the regular expression in the name, the compiled expressions
+/// are cached
+#[cfg(feature = "regex")]
+fn is_regex_matched(pattern: &str, name: &str) -> bool {
+    use std::sync::{LazyLock, Mutex};
+    static REGEXES: LazyLock<Mutex<HashMap<String, Option<regex::Regex>>>> =
+        LazyLock::new(Default::default);
+
+    let mut regexes = REGEXES.lock().unwrap_or_else(|e| e.into_inner());
+    regexes
+        .entry(pattern.to_owned())
+        .or_insert_with(|| regex::Regex::new(pattern).ok())
+        .as_ref()
+        .is_some_and(|regex| regex.is_match(name))
+}
+
+/// Without the `regex` feature, the regular expressions are rejected by
+/// [`FilterConfig::check_patterns`]
+#[cfg(not(feature = "regex"))]
+fn is_regex_matched(_pattern: &str, _name: &str) -> bool {
+    false
+}
+
+/// Returns the filter of the schema listed by its name, or by the most
+/// specific pattern matching it
+fn find_schema_filter<'a>(
+    schemas: &'a HashMap<String, SchemaFilter>,
+    schema_name: &str,
+) -> Option<&'a SchemaFilter> {
+    if let Some(filter) = schemas.get(schema_name) {
+        return Some(filter);
+    }
+    schemas
+        .iter()
+        .map(|(pattern, filter)| (Pattern::parse(pattern), filter))
+        .filter(|(pattern, _)| pattern.is_matched(schema_name))
+        // ties are broken by the pattern itself to be independent of the
+        // hash order
+        .max_by(|(a, _), (b, _)| a.precedence().cmp(&b.precedence()).then(b.cmp(a)))
+        .map(|(_, filter)| filter)
+}
+
+impl FilterConfig {
+    /// Returns `true` if the enumeration gets the `Unknown` variant
+    pub(crate) fn has_unknown_variant(&self, enum_name: &str) -> bool {
+        self.unknown_enum_variants
+            .iter()
+            .any(|name| name == "*" || name == enum_name)
+    }
+
+    /// Returns the most specific mapping of the type for the field. If there
+    /// are several of them, the first one is taken.
+    pub(crate) fn find_type_mapping(
+        &self,
+        schema_name: &str,
+        field_name: &str,
+        type_: &str,
+        format: &str,
+    ) -> Option<TypeMapping> {
+        let find = |mappings: &[TypeMapping]| {
+            let mut found: Option<&TypeMapping> = None;
+            for mapping in mappings {
+                if mapping.is_matched(schema_name, field_name, type_, format)
+                    && found.is_none_or(|f| f.precedence() < mapping.precedence())
+                {
+                    found = Some(mapping);
+                }
+            }
+            found.cloned()
+        };
+        find(&self.type_mappings).or_else(|| {
+            let mut mappings = get_default_type_mappings(self.date_time_backend);
+            if self.standard_formats {
+                mappings.extend(get_standard_format_mappings());
+            }
+            find(&mappings)
+        })
+    }
+
+    /// Returns `true` if the generated types must implement
+    /// `serde::Serialize`
+    pub(crate) fn is_serialize_enabled(&self) -> bool {
+        self.serialize
+            || self
+                .struct_derives
+                .iter()
+                .chain(&self.enum_derives)
+                .any(|d| d == "Serialize")
+    }
+
+    /// Read configuration from string in JSON or YAML format
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(data: &str) -> Result<Self, Error> {
+        resolving::parse_document(data)
+    }
+
+    /// Read configuration from file, the format is determined by the
+    /// extension (`json`, `yaml` or `yml`)
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        resolving::read_document(path.as_ref())
+    }
+
+    /// Returns `true` if the schemas are selected by the API operations
+    pub(crate) fn has_operation_filter(&self) -> bool {
+        !self.include_operations.is_empty()
+            || !self.include_tags.is_empty()
+            || !self.exclude_tags.is_empty()
+    }
+
+    /// Returns `true` if the operation with the tags is selected by
+    /// `include_tags` and `exclude_tags`
+    pub(crate) fn is_tag_accepted(&self, tags: &[String]) -> bool {
+        if tags.iter().any(|t| self.exclude_tags.contains(t)) {
+            return false;
         }
+        if !self.include_tags.is_empty() {
+            return tags.iter().any(|t| self.include_tags.contains(t));
+        }
+        !self.exclude_tags.is_empty()
     }
 
-    pub(super) fn is_schema_present(&self, schema_name: &str) -> bool {
-        // Just check if the schema is found somewhere in the filter
-        if let Some(schemas) = &self.include {
-            return schemas.contains_key(schema_name);
+    /// Returns the problems of the regular expressions in the filter that are
+    /// invalid or not supported
+    pub(crate) fn check_patterns(&self) -> Vec<Error> {
+        let mut errors = vec![];
+        let sections = self.include.iter().chain(&self.exclude);
+        for (name, filter) in sections.flatten() {
+            let properties = match filter {
+                SchemaFilter::AcceptAll(_) => &[][..],
+                SchemaFilter::AcceptSelected(properties) => properties,
+            };
+            for pattern in std::iter::once(name).chain(properties) {
+                if let Some(message) = Pattern::parse(pattern).error() {
+                    errors.push(Error::InvalidPattern {
+                        pattern: pattern.clone(),
+                        message,
+                    });
+                }
+            }
         }
-        if let Some(schemas) = &self.exclude {
-            return schemas.contains_key(schema_name);
+        errors
+    }
+
+    /// Returns `true` if the schema is listed in the inclusions
+    pub(super) fn is_schema_included(&self, schema_name: &str) -> bool {
+        self.include
+            .as_ref()
+            .is_some_and(|schemas| find_schema_filter(schemas, schema_name).is_some())
+    }
+
+    /// Returns `true` if the schema is excluded entirely, i.e. with `*`
+    pub(super) fn is_schema_excluded(&self, schema_name: &str) -> bool {
+        let filter = self
+            .exclude
+            .as_ref()
+            .and_then(|s| find_schema_filter(s, schema_name));
+        matches!(filter, Some(SchemaFilter::AcceptAll(_)))
+    }
+
+    /// Returns `true` if the property is listed in the exclusions of the
+    /// schema
+    pub(super) fn is_property_excluded(&self, schema_name: &str, property_name: &str) -> bool {
+        let filter = self
+            .exclude
+            .as_ref()
+            .and_then(|s| find_schema_filter(s, schema_name));
+        match filter {
+            Some(filter @ SchemaFilter::AcceptSelected(_)) => filter.is_accepted(property_name),
+            // the schema excluded entirely is not generated at all
+            Some(SchemaFilter::AcceptAll(_)) | None => false,
         }
-        false
     }
 
     pub(super) fn is_schema_accepted(&self, schema_name: &str) -> bool {
-        // if a list of inclusions is specified
-        if let Some(schemas) = &self.include {
-            // then only the listed ones are suitable
-            return schemas.contains_key(schema_name);
-        }
-        // if a list of exceptions is specified
-        if let Some(schemas) = &self.exclude {
-            return match schemas.get(schema_name) {
-                // it is suitable only if some fields are specified (we will
-                // deal with them in method is_property_accepted)
-                Some(schema) => matches!(schema, SchemaFilter::AcceptSelected(_)),
-                // there is no such on the blacklist, it is suitable
-                None => true,
-            };
+        // the exclusions are subtracted from the inclusions; if some fields
+        // are excluded, the schema is still suitable (we will deal with them
+        // in method is_property_accepted)
+        if self.is_schema_excluded(schema_name) {
+            return false;
+        }
+        // if a list of inclusions is specified, then only the listed ones are
+        // suitable, otherwise all are suitable
+        match &self.include {
+            Some(schemas) => find_schema_filter(schemas, schema_name).is_some(),
+            None => true,
         }
-        // if nothing is specified, then all are suitable
-        true
     }
 
     pub(super) fn is_property_accepted(&self, schema_name: &str, property_name: &str) -> bool {
-        if self.auto_include_dependencies && !self.is_schema_present(schema_name) {
-            // in this case, automatic include is used
-            return true;
+        // the exclusions are subtracted from the inclusions
+        if self.is_property_excluded(schema_name, property_name) {
+            return false;
         }
         // if a list of inclusions is specified, then only those listed are
         // suitable
-        if let Some(schemas) = &self.include {
-            return match schemas.get(schema_name) {
+        match &self.include {
+            Some(schemas) => match find_schema_filter(schemas, schema_name) {
                 Some(filter) => filter.is_accepted(property_name),
-                None => false,
-            };
+                // the schema that is not listed can only be included
+                // automatically as a dependency, with all its properties
+                None => self.auto_include_dependencies,
+            },
+            // if nothing is specified, then all are suitable
+            None => true,
+        }
+    }
+
+    /// Finds the entries of `include` and `exclude` that have no effect on
+    /// the schemas of the specification, and describes them
+    pub(crate) fn find_ineffective_entries(&self, spec: &OpenApi) -> Vec<String> {
+        let mut messages = vec![];
+        let mut schema_names: Vec<_> = spec.components.schemas.keys().collect();
+        schema_names.sort();
+        let matched_schemas = |pattern: &str| -> Vec<&String> {
+            let pattern = Pattern::parse(pattern);
+            schema_names
+                .iter()
+                .filter(|name| pattern.is_matched(name))
+                .copied()
+                .collect()
+        };
+        // the schemas that are not included can be pulled in as the
+        // dependencies
+        let is_include_complete = self.include.is_some()
+            && !self.auto_include_dependencies
+            && !self.has_operation_filter();
+
+        let mut includes: Vec<_> = self.include.iter().flatten().collect();
+        includes.sort_by(|a, b| a.0.cmp(b.0));
+        for (pattern, _) in includes {
+            let schemas = matched_schemas(pattern);
+            if !schemas.is_empty() && schemas.iter().all(|s| self.is_schema_excluded(s)) {
+                messages.push(format!(
+                    "`include` entry {pattern:?} has no effect, it is excluded"
+                ));
+            }
         }
-        // if a list of exceptions is specified, then only those listed are
-        // not suitable
-        if let Some(schemas) = &self.exclude {
-            return match schemas.get(schema_name) {
-                Some(filter) => !filter.is_accepted(property_name),
-                None => true,
+
+        let mut excludes: Vec<_> = self.exclude.iter().flatten().collect();
+        excludes.sort_by(|a, b| a.0.cmp(b.0));
+        for (pattern, filter) in excludes {
+            let schemas = matched_schemas(pattern);
+            if !is_include_complete || schemas.is_empty() {
+                continue;
+            }
+            if !schemas.iter().any(|s| self.is_schema_included(s)) {
+                messages.push(format!(
+                    "`exclude` entry {pattern:?} has no effect, it matches no included schema"
+                ));
+                continue;
+            }
+            let SchemaFilter::AcceptSelected(properties) = filter else {
+                continue;
             };
+            // the properties that are not included are not generated anyway
+            for property in properties {
+                let property_pattern = Pattern::parse(property);
+                let is_included = |schema_name: &&String| {
+                    let include = self
+                        .include
+                        .as_ref()
+                        .and_then(|s| find_schema_filter(s, schema_name));
+                    match include {
+                        Some(SchemaFilter::AcceptSelected(items)) => items.iter().any(|item| {
+                            !matches!(Pattern::parse(item), Pattern::Exact(_))
+                                || property_pattern.is_matched(item)
+                        }),
+                        _ => true,
+                    }
+                };
+                if !schemas.iter().any(is_included) {
+                    messages.push(format!(
+                        "`exclude` entry {pattern:?} has no effect on property {property:?}, it is not included"
+                    ));
+                }
+            }
+        }
+        messages
+    }
+
+    /// Checks the entries of `include` and `exclude` against the schemas of
+    /// the specification: the names of the schemas and the properties must
+    /// exist (or be matched by the patterns), and the only string allowed
+    /// instead of the list of properties is `"*"`. The names of the nested
+    /// objects (like `UserAddress`) are only known after the processing, so
+    /// the specification is processed only if an entry does not match the
+    /// components. The locations are JSON pointers in the filter, like
+    /// `#/include/Pet`.
+    pub(crate) fn find_invalid_entries(&self, spec: &OpenApi) -> Vec<Error> {
+        let mut errors = vec![];
+        let mut schema_names: Vec<_> = spec.components.schemas.keys().collect();
+        schema_names.sort();
+        let mut inline_types = None;
+        for (section, schemas) in [("include", &self.include), ("exclude", &self.exclude)] {
+            let mut schemas: Vec<_> = schemas.iter().flatten().collect();
+            schemas.sort_by(|a, b| a.0.cmp(b.0));
+            for (pattern, filter) in schemas {
+                let location = processing::pointer_to(&format!("#/{section}"), pattern);
+                let schema_pattern = Pattern::parse(pattern);
+                if schema_pattern.error().is_some() {
+                    // already reported by `check_patterns`
+                    continue;
+                }
+                let properties = match filter {
+                    SchemaFilter::AcceptAll(_) => &[][..],
+                    SchemaFilter::AcceptSelected(properties) => properties,
+                };
+                let is_known = |known_properties: &[String], property: &str| {
+                    let property_pattern = Pattern::parse(property);
+                    // the invalid patterns are reported by `check_patterns`
+                    property_pattern.error().is_some()
+                        || known_properties
+                            .iter()
+                            .any(|p| property_pattern.is_matched(p))
+                };
+
+                let matched: Vec<_> = (schema_names.iter())
+                    .filter(|name| schema_pattern.is_matched(name))
+                    .collect();
+                let mut is_matched = !matched.is_empty();
+                let mut known_properties: Vec<_> = (matched.iter())
+                    .flat_map(|name| processing::schema_property_names(spec, name))
+                    .collect();
+                if !is_matched || !properties.iter().all(|p| is_known(&known_properties, p)) {
+                    let inline_types = inline_types
+                        .get_or_insert_with(|| processing::process_inline_types(spec, self));
+                    for dt in inline_types.iter() {
+                        if !schema_pattern.is_matched(dt.schema_name()) {
+                            continue;
+                        }
+                        is_matched = true;
+                        if let DataType::Struct { fields, .. } = dt {
+                            // the catch-all field of `additionalProperties` is
+                            // the only flattened one selected by its name
+                            let fields = fields.iter().filter(|field| {
+                                !field.is_flatten || field.name == "additionalProperties"
+                            });
+                            known_properties.extend(fields.map(|field| field.name.clone()));
+                        }
+                    }
+                }
+
+                if !is_matched {
+                    errors.push(Error::InvalidFilter {
+                        location,
+                        message: format!("unknown schema {pattern:?}"),
+                    });
+                    continue;
+                }
+                if let SchemaFilter::AcceptAll(all) = filter
+                    && all != "*"
+                {
+                    errors.push(Error::InvalidFilter {
+                        location,
+                        message: format!("expected \"*\" or a list of properties, found {all:?}"),
+                    });
+                    continue;
+                }
+                for property in properties {
+                    if !is_known(&known_properties, property) {
+                        errors.push(Error::InvalidFilter {
+                            location: location.clone(),
+                            message: format!("unknown property {property:?}"),
+                        });
+                    }
+                }
+            }
         }
-        // if nothing is specified, then all are suitable
-        true
+        errors
     }
 }
@@ -1,19 +1,22 @@
 #![doc = include_str!("../README.md")]
 
 mod datatypes;
+mod error;
 mod filter;
 mod openapi;
 mod processing;
+mod resolving;
+mod selecting;
 mod writing;
 
 #[cfg(test)]
 mod tests;
 
 // exported
+pub use error::Error;
 pub use filter::FilterConfig;
 pub use openapi::OpenApi;
 
-use std::error::Error;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
@@ -22,45 +25,118 @@ use std::path::Path;
 /// fails, it returns an error, and the specified file will be cleared (if it
 /// exists and it was opened).
 ///
+/// # Warnings
+///
+/// Unlike the other functions, this one prints the warnings (see
+/// [`generate_openapi_types_with_warnings`]) itself: as `cargo:warning` when
+/// it is called from a build script (where `OUT_DIR` is set), so that Cargo
+/// shows them, and to stderr otherwise. To handle the warnings in another
+/// way, call [`generate_openapi_types_with_warnings`] and write the file
+/// yourself.
+///
 /// # Example
 /// ```no_run
+/// # use openapi_type_picker::*;
+/// # fn main() -> Result<(), Error> {
 /// write_openapi_types(
-///     OpenApi::from_file("../schema.json"),
-///     FilterConfig::from_file("../config.json"),
+///     OpenApi::from_file("../schema.json")?,
+///     FilterConfig::from_file("../config.json")?,
 ///     "src/api/types.rs"
-/// ).unwrap();
+/// )?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn write_openapi_types<P: AsRef<Path>>(
     openapi: OpenApi,
     config: FilterConfig,
     out_file: P,
-) -> Result<(), Box<dyn Error>> {
-    let mut file = File::create(out_file)?;
-    let s = generate_openapi_types(openapi, config)?;
-    file.write_all(s.as_bytes())?;
+) -> Result<(), Error> {
+    let path = out_file.as_ref();
+    let io_error = |source| Error::Io {
+        path: path.to_owned(),
+        source,
+    };
+    let mut file = File::create(path).map_err(io_error)?;
+    let (s, warnings) = match generate_openapi_types_with_warnings(openapi, config) {
+        Ok(generated) => generated,
+        Err(e) => {
+            for warning in e.warnings() {
+                error::warn(warning);
+            }
+            return Err(e.without_warnings());
+        }
+    };
+    for warning in &warnings {
+        error::warn(warning);
+    }
+    file.write_all(s.as_bytes()).map_err(io_error)?;
     Ok(())
 }
 
-/// Generates types according to the OpenAPI specification to a [`String`]
+/// Generates types according to the OpenAPI specification to a [`String`].
+/// The warnings are discarded, use [`generate_openapi_types_with_warnings`]
+/// to get them.
 ///
 /// # Example
 /// ```no_run
+/// # use openapi_type_picker::*;
+/// # fn main() -> Result<(), Error> {
 /// let s = generate_openapi_types(
-///     OpenApi::from_file("../schema.json"),
-///     FilterConfig::from_file("../config.json")
-/// ).unwrap();
+///     OpenApi::from_file("../schema.json")?,
+///     FilterConfig::from_file("../config.json")?
+/// )?;
 /// println!("{s:?}");
+/// # Ok(())
+/// # }
 /// ```
-pub fn generate_openapi_types(
+pub fn generate_openapi_types(openapi: OpenApi, config: FilterConfig) -> Result<String, Error> {
+    generate_openapi_types_with_warnings(openapi, config)
+        .map(|(s, _)| s)
+        .map_err(Error::without_warnings)
+}
+
+/// Generates types according to the OpenAPI specification to a [`String`],
+/// returning it along with the warnings: the problems that do not prevent
+/// the generation, like the filter entries that have no effect. If the
+/// generation fails anyway, and there are warnings, the error is
+/// [`Error::WithWarnings`], which keeps them (see [`Error::warnings`]).
+///
+/// # Example
+/// ```no_run
+/// # use openapi_type_picker::*;
+/// # fn main() -> Result<(), Error> {
+/// let (s, warnings) = generate_openapi_types_with_warnings(
+///     OpenApi::from_file("../schema.json")?,
+///     FilterConfig::from_file("../config.json")?
+/// )?;
+/// for warning in warnings {
+///     eprintln!("warning: {warning}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn generate_openapi_types_with_warnings(
     openapi: OpenApi,
     config: FilterConfig,
-) -> Result<String, Box<dyn Error>> {
-    let mut datatypes = processing::process_components(&openapi, &config)?;
-    let missing_schemas = processing::find_missing_schemas(&datatypes);
-    if !missing_schemas.is_empty() {
-        let msg = format!("Found reference to missing schemas: {:?}", missing_schemas);
-        return Err(msg.into());
+) -> Result<(String, Vec<String>), Error> {
+    let mut warnings = vec![];
+    match generate(&openapi, &config, &mut warnings) {
+        Ok(s) => Ok((s, warnings)),
+        Err(e) if warnings.is_empty() => Err(e),
+        Err(e) => Err(Error::WithWarnings {
+            error: Box::new(e),
+            warnings,
+        }),
     }
+}
+
+/// Generates the code, collecting the warnings into `warnings`
+fn generate(
+    openapi: &OpenApi,
+    config: &FilterConfig,
+    warnings: &mut Vec<String>,
+) -> Result<String, Error> {
+    let mut datatypes = processing::process_components(openapi, config, true, warnings)?;
 
     // sort data types to reduce the changes in the version control system
     datatypes.sort_by(|a, b| a.schema_name().cmp(b.schema_name()));
@@ -70,12 +146,7 @@ pub fn generate_openapi_types(
 
     let mut buf = String::with_capacity(1024);
     writing::write_comment_header(&mut buf)?;
-    writing::write_rust_code(
-        &mut buf,
-        &datatypes,
-        &config.struct_derives,
-        &config.enum_derives,
-    )?;
+    writing::write_rust_code(&mut buf, &datatypes, config)?;
 
     // removing the double line break to appease rustfmt
     buf.pop();
@@ -1,6 +1,9 @@
-use serde::Deserialize;
+use crate::error::Error;
+use crate::processing::pointer_to;
+use crate::resolving;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
-use std::fs::File;
 use std::path::Path;
 
 /// OpenAPI root (minimal)
@@ -9,17 +12,263 @@ use std::path::Path;
 /// <https://learn.openapis.org/specification/>
 #[derive(Deserialize)]
 pub struct OpenApi {
+    #[serde(default)]
     pub components: Components,
+    /// API operations by the path, like `/pets/{id}`
+    #[serde(default, deserialize_with = "deserialize_without_extensions")]
+    pub paths: HashMap<String, PathItem>,
+    /// Schemas of Swagger 2.0, they are moved to the components while
+    /// reading the specification
+    #[serde(default)]
+    definitions: HashMap<String, Schema>,
+    /// Parameters of Swagger 2.0, they are moved to the components as well
+    #[serde(default)]
+    parameters: HashMap<String, Parameter>,
+    /// Responses of Swagger 2.0, they are moved to the components as well
+    #[serde(default)]
+    responses: HashMap<String, Response>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 pub struct Components {
+    #[serde(default)]
     pub schemas: HashMap<String, Schema>,
+    #[serde(default)]
+    pub parameters: HashMap<String, Parameter>,
+    #[serde(rename = "requestBodies", default)]
+    pub request_bodies: HashMap<String, RequestBody>,
+    #[serde(default)]
+    pub responses: HashMap<String, Response>,
 }
 
-/// Universal description of the scheme
+/// Operations available on a single path
+#[derive(Deserialize, Default)]
+pub struct PathItem {
+    /// Parameters shared by all the operations
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    pub get: Option<Operation>,
+    pub put: Option<Operation>,
+    pub post: Option<Operation>,
+    pub delete: Option<Operation>,
+    pub options: Option<Operation>,
+    pub head: Option<Operation>,
+    pub patch: Option<Operation>,
+    pub trace: Option<Operation>,
+}
+
+impl PathItem {
+    /// Returns the references of the parameters, the request bodies and the
+    /// responses of all the operations
+    fn refs_mut(&mut self) -> Vec<&mut String> {
+        let operations = [
+            &mut self.get,
+            &mut self.put,
+            &mut self.post,
+            &mut self.delete,
+            &mut self.options,
+            &mut self.head,
+            &mut self.patch,
+            &mut self.trace,
+        ];
+        let mut refs: Vec<_> = (self.parameters.iter_mut())
+            .filter_map(|p| p.ref_.as_mut())
+            .collect();
+        for operation in operations.into_iter().flatten() {
+            refs.extend(
+                operation
+                    .parameters
+                    .iter_mut()
+                    .filter_map(|p| p.ref_.as_mut()),
+            );
+            refs.extend(
+                operation
+                    .request_body
+                    .iter_mut()
+                    .filter_map(|b| b.ref_.as_mut()),
+            );
+            refs.extend(
+                operation
+                    .responses
+                    .values_mut()
+                    .filter_map(|r| r.ref_.as_mut()),
+            );
+        }
+        refs
+    }
+
+    /// Returns the schemas of the parameters, the request bodies and the
+    /// responses of all the operations along with their locations, `pointer`
+    /// is the location of the path item
+    fn schemas_mut(&mut self, pointer: &str) -> Vec<(String, &mut Schema)> {
+        let mut schemas = vec![];
+        let operations = [
+            ("get", &mut self.get),
+            ("put", &mut self.put),
+            ("post", &mut self.post),
+            ("delete", &mut self.delete),
+            ("options", &mut self.options),
+            ("head", &mut self.head),
+            ("patch", &mut self.patch),
+            ("trace", &mut self.trace),
+        ];
+        let parameters_pointer = pointer_to(pointer, "parameters");
+        let mut parameters: Vec<_> = (self.parameters.iter_mut().enumerate())
+            .map(|(i, p)| (pointer_to(&parameters_pointer, &i.to_string()), p))
+            .collect();
+        let mut contents = vec![];
+        for (method, operation) in operations {
+            let Some(operation) = operation else {
+                continue;
+            };
+            let pointer = pointer_to(pointer, method);
+            let parameters_pointer = pointer_to(&pointer, "parameters");
+            parameters.extend(
+                (operation.parameters.iter_mut().enumerate())
+                    .map(|(i, p)| (pointer_to(&parameters_pointer, &i.to_string()), p)),
+            );
+            if let Some(body) = &mut operation.request_body {
+                contents.push((pointer_to(&pointer, "requestBody"), &mut body.content));
+            }
+            let responses_pointer = pointer_to(&pointer, "responses");
+            for (code, response) in sorted_entries_mut(&mut operation.responses) {
+                let pointer = pointer_to(&responses_pointer, code);
+                if let Some(schema) = &mut response.schema {
+                    schemas.push((pointer_to(&pointer, "schema"), schema));
+                }
+                contents.push((pointer, &mut response.content));
+            }
+        }
+        for (pointer, parameter) in parameters {
+            if let Some(schema) = &mut parameter.schema {
+                schemas.push((pointer_to(&pointer, "schema"), schema));
+            }
+            contents.push((pointer, &mut parameter.content));
+        }
+        for (pointer, content) in contents {
+            schemas.extend(content_schemas_mut(&pointer, content));
+        }
+        schemas
+    }
+
+    /// Returns the operations with their methods in upper case, like `GET`
+    pub fn operations(&self) -> Vec<(&'static str, &Operation)> {
+        [
+            ("GET", &self.get),
+            ("PUT", &self.put),
+            ("POST", &self.post),
+            ("DELETE", &self.delete),
+            ("OPTIONS", &self.options),
+            ("HEAD", &self.head),
+            ("PATCH", &self.patch),
+            ("TRACE", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+        .collect()
+    }
+}
+
+/// Single API operation (minimal)
 #[derive(Deserialize)]
+pub struct Operation {
+    #[serde(rename = "operationId")]
+    pub operation_id: Option<String>,
+    /// Groups of the operations, like `billing`
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    #[serde(rename = "requestBody")]
+    pub request_body: Option<RequestBody>,
+    /// Responses by the status code, like `200` or `default`
+    #[serde(default, deserialize_with = "deserialize_without_extensions")]
+    pub responses: HashMap<String, Response>,
+}
+
+/// Parameter of an operation, or a reference to the one from the components
+#[derive(Deserialize)]
+pub struct Parameter {
+    #[serde(rename = "$ref")]
+    pub ref_: Option<String>,
+    pub schema: Option<Schema>,
+    #[serde(default)]
+    pub content: HashMap<String, MediaType>,
+}
+
+/// Body of a request, or a reference to the one from the components
+#[derive(Deserialize)]
+pub struct RequestBody {
+    #[serde(rename = "$ref")]
+    pub ref_: Option<String>,
+    #[serde(default)]
+    pub content: HashMap<String, MediaType>,
+}
+
+/// Response of an operation, or a reference to the one from the components
+#[derive(Deserialize)]
+pub struct Response {
+    #[serde(rename = "$ref")]
+    pub ref_: Option<String>,
+    #[serde(default)]
+    pub content: HashMap<String, MediaType>,
+    /// Schema of the body in Swagger 2.0
+    pub schema: Option<Schema>,
+}
+
+/// Content of a request or a response of some media type, like
+/// `application/json`
+#[derive(Deserialize)]
+pub struct MediaType {
+    pub schema: Option<Schema>,
+}
+
+/// Returns the schemas of the media types along with their locations,
+/// `pointer` is the location of the content owner
+fn content_schemas_mut<'a>(
+    pointer: &str,
+    content: &'a mut HashMap<String, MediaType>,
+) -> Vec<(String, &'a mut Schema)> {
+    let content_pointer = pointer_to(pointer, "content");
+    (sorted_entries_mut(content).into_iter())
+        .filter_map(|(media_type, m)| {
+            let pointer = pointer_to(&pointer_to(&content_pointer, media_type), "schema");
+            Some((pointer, m.schema.as_mut()?))
+        })
+        .collect()
+}
+
+/// Returns the entries of the map sorted by the keys, so that the result
+/// does not depend on the hash order
+fn sorted_entries_mut<T>(map: &mut HashMap<String, T>) -> Vec<(&String, &mut T)> {
+    let mut entries: Vec<_> = map.iter_mut().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+/// Reads a map skipping the extensions like `x-internal`, whose values can
+/// be anything
+fn deserialize_without_extensions<'de, D, T>(
+    deserializer: D,
+) -> Result<HashMap<String, T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    HashMap::<String, serde_json::Value>::deserialize(deserializer)?
+        .into_iter()
+        .filter(|(key, _)| !key.starts_with("x-"))
+        .map(|(key, value)| {
+            let value = serde_json::from_value(value).map_err(serde::de::Error::custom)?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// Universal description of the scheme
+#[derive(Clone, Deserialize, PartialEq)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum Schema {
     /// Link to another scheme
     Ref {
@@ -29,7 +278,7 @@ pub enum Schema {
     /// Scheme with "type" property
     Typed {
         #[serde(rename = "type", default)]
-        schema_type: String,
+        schema_type: SchemaType,
 
         #[serde(default)]
         format: String,
@@ -46,12 +295,36 @@ pub enum Schema {
         #[serde(default)]
         required: Vec<String>,
 
+        /// Values of the object properties that are not listed in
+        /// "properties"
+        #[serde(rename = "additionalProperties")]
+        additional_properties: Option<AdditionalProperties>,
+
         /// Array items if `{"type": "array"}`
         items: Option<Box<Schema>>,
 
         /// Enumeration elements
         #[serde(rename = "enum")]
-        enum_items: Option<Vec<String>>,
+        enum_items: Option<Vec<serde_json::Value>>,
+
+        /// Names of the enumeration elements in the same order, used as the
+        /// names of the variants (`x-enum-varnames` or `x-enumNames`
+        /// extension)
+        #[serde(rename = "x-enum-varnames", alias = "x-enumNames")]
+        enum_names: Option<Vec<String>>,
+
+        /// The only allowed value (OpenAPI 3.1), the same as an enumeration
+        /// with one element
+        #[serde(rename = "const")]
+        const_value: Option<serde_json::Value>,
+
+        /// Local definitions (OpenAPI 3.1), they are moved to the components
+        /// while reading the specification
+        #[serde(rename = "$defs")]
+        defs: Option<HashMap<String, Schema>>,
+
+        /// Hint which of the `oneOf` schemas is used
+        discriminator: Option<Discriminator>,
 
         /// Compositions
         #[serde(rename = "allOf")]
@@ -63,25 +336,573 @@ pub enum Schema {
     },
 }
 
-impl OpenApi {
-    /// Read specifiaction from string
-    pub fn from_str(data: &str) -> Self {
-        if data.trim_start().starts_with("{") {
-            serde_json::from_str(data).unwrap()
-        } else {
-            serde_yaml::from_str(data).unwrap()
+/// Determines which of the `oneOf` schemas is used by the value of a
+/// property
+#[derive(Clone, PartialEq)]
+pub struct Discriminator {
+    /// Name of the property containing the value
+    pub property_name: String,
+    /// Values of the property and references to the schemas. By default,
+    /// the value is the name of the schema.
+    pub mapping: HashMap<String, String>,
+}
+
+impl<'de> Deserialize<'de> for Discriminator {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Swagger 2.0 uses just the name of the property, OpenAPI 3 uses an
+        // object with an optional mapping
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            PropertyName(String),
+            Object {
+                #[serde(rename = "propertyName")]
+                property_name: String,
+                #[serde(default)]
+                mapping: HashMap<String, String>,
+            },
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::PropertyName(property_name) => Discriminator {
+                property_name,
+                mapping: HashMap::new(),
+            },
+            Raw::Object {
+                property_name,
+                mapping,
+            } => Discriminator {
+                property_name,
+                mapping,
+            },
+        })
+    }
+}
+
+impl Discriminator {
+    /// Returns the value of the property for the schema
+    pub fn value_for(&self, schema_name: &str) -> String {
+        // the mapping can contain both references and just names
+        let mut values: Vec<_> = self
+            .mapping
+            .iter()
+            .filter(|(_, ref_)| ref_.rsplit('/').next() == Some(schema_name))
+            .map(|(value, _)| value)
+            .collect();
+        values.sort();
+        match values.first() {
+            Some(value) => (*value).clone(),
+            None => schema_name.to_owned(),
+        }
+    }
+}
+
+/// Value of the "additionalProperties" property: either a flag or a schema
+/// of values
+#[derive(Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum AdditionalProperties {
+    /// `true` allows values of any type, `false` prohibits additional
+    /// properties
+    Allowed(bool),
+    /// Values must match the schema
+    Schema(Box<Schema>),
+}
+
+impl AdditionalProperties {
+    /// Returns `true` if additional properties are allowed
+    pub fn is_allowed(&self) -> bool {
+        !matches!(self, AdditionalProperties::Allowed(false))
+    }
+}
+
+/// Value of the "type" property: a single type in OpenAPI 3.0, or an array
+/// of types in OpenAPI 3.1, like `["string", "null"]`
+#[derive(Clone, Default, PartialEq)]
+pub struct SchemaType(Vec<String>);
+
+impl<'de> Deserialize<'de> for SchemaType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // in YAML, an unquoted `null` is not a string, but it still means
+        // the "null" type
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Single(Option<String>),
+            Multiple(Vec<Option<String>>),
+        }
+
+        let types = match Raw::deserialize(deserializer)? {
+            Raw::Single(t) => vec![t],
+            Raw::Multiple(types) => types,
+        };
+        Ok(SchemaType(
+            types
+                .into_iter()
+                .map(|t| t.unwrap_or_else(|| "null".into()))
+                .collect(),
+        ))
+    }
+}
+
+impl SchemaType {
+    /// Returns `true` if the type is not specified
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if "null" is one of the allowed types
+    pub fn is_nullable(&self) -> bool {
+        self.0.iter().any(|t| t == "null")
+    }
+
+    /// Returns `true` if "null" is the only allowed type
+    pub fn is_null(&self) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|t| t == "null")
+    }
+
+    /// All allowed types, except "null"
+    pub fn non_null(&self) -> Vec<String> {
+        self.0.iter().filter(|t| *t != "null").cloned().collect()
+    }
+}
+
+impl Schema {
+    /// Returns `true` if the schema only allows `null`, like `{"type":
+    /// "null"}` in OpenAPI 3.1
+    pub fn is_null(&self) -> bool {
+        match self {
+            Schema::Ref { .. } => false,
+            Schema::Typed { schema_type, .. } => schema_type.is_null(),
         }
     }
 
-    /// Read specifiaction from file
-    pub fn from_file<P: AsRef<Path>>(path: P) -> Self {
+    /// Returns `true` if the schema does not describe a type, but only adds
+    /// a description or `nullable`, like the `allOf` members
+    /// `{"nullable": true}`. The members with `required` are not annotations,
+    /// they change the merged object.
+    pub fn is_annotation(&self) -> bool {
+        match self {
+            Schema::Ref { .. } => false,
+            Schema::Typed {
+                schema_type,
+                properties,
+                required,
+                additional_properties,
+                items,
+                enum_items,
+                all_of,
+                one_of,
+                any_of,
+                ..
+            } => {
+                schema_type.non_null().is_empty()
+                    && properties.is_none()
+                    && required.is_empty()
+                    && additional_properties.is_none()
+                    && items.is_none()
+                    && enum_items.is_none()
+                    && all_of.is_none()
+                    && one_of.is_none()
+                    && any_of.is_none()
+            }
+        }
+    }
+
+    /// Returns `true` if the schemas differ only in the annotations, like
+    /// `description`, which do not change the generated type
+    pub fn is_same_type(&self, other: &Schema) -> bool {
+        let without_annotations = |schema: &Schema| {
+            let mut schema = schema.clone();
+            schema.visit_mut(&mut |schema| {
+                if let Schema::Typed { description, .. } = schema {
+                    description.clear();
+                }
+            });
+            schema
+        };
+        without_annotations(self) == without_annotations(other)
+    }
+
+    /// Returns `true` if the schema explicitly allows `null`, including
+    /// `null` listed in `enum`
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Schema::Ref { .. } => false,
+            Schema::Typed {
+                schema_type,
+                nullable,
+                enum_items,
+                ..
+            } => {
+                *nullable
+                    || schema_type.is_nullable()
+                    || enum_items.iter().flatten().any(|value| value.is_null())
+            }
+        }
+    }
+
+    /// Calls `f` for this schema and then for all nested schemas
+    pub(crate) fn visit(&self, f: &mut dyn FnMut(&Schema)) {
+        f(self);
+        if let Schema::Typed {
+            properties,
+            additional_properties,
+            items,
+            defs,
+            all_of,
+            one_of,
+            any_of,
+            ..
+        } = self
+        {
+            for schema in properties.iter().flat_map(|p| p.values()) {
+                schema.visit(f);
+            }
+            if let Some(AdditionalProperties::Schema(schema)) = additional_properties {
+                schema.visit(f);
+            }
+            if let Some(schema) = items {
+                schema.visit(f);
+            }
+            for schema in defs.iter().flat_map(|d| d.values()) {
+                schema.visit(f);
+            }
+            for schemas in [all_of, one_of, any_of].into_iter().flatten() {
+                for schema in schemas {
+                    schema.visit(f);
+                }
+            }
+        }
+    }
+
+    /// Calls `f` for this schema and then for all nested schemas
+    pub(crate) fn visit_mut(&mut self, f: &mut dyn FnMut(&mut Schema)) {
+        f(self);
+        if let Schema::Typed {
+            properties,
+            additional_properties,
+            items,
+            defs,
+            all_of,
+            one_of,
+            any_of,
+            ..
+        } = self
+        {
+            for schema in properties.iter_mut().flat_map(|p| p.values_mut()) {
+                schema.visit_mut(f);
+            }
+            if let Some(AdditionalProperties::Schema(schema)) = additional_properties {
+                schema.visit_mut(f);
+            }
+            if let Some(schema) = items {
+                schema.visit_mut(f);
+            }
+            for schema in defs.iter_mut().flat_map(|d| d.values_mut()) {
+                schema.visit_mut(f);
+            }
+            for schemas in [all_of, one_of, any_of].into_iter().flatten() {
+                for schema in schemas {
+                    schema.visit_mut(f);
+                }
+            }
+        }
+    }
+
+    /// Calls `f` for this schema and then for all nested schemas along with
+    /// their JSON pointers, `pointer` is the location of this schema
+    pub(crate) fn visit_with_pointer_mut(
+        &mut self,
+        pointer: &str,
+        f: &mut dyn FnMut(&str, &mut Schema),
+    ) {
+        f(pointer, self);
+        if let Schema::Typed {
+            properties,
+            additional_properties,
+            items,
+            defs,
+            all_of,
+            one_of,
+            any_of,
+            ..
+        } = self
+        {
+            let properties_pointer = pointer_to(pointer, "properties");
+            for (name, schema) in properties.iter_mut().flatten() {
+                schema.visit_with_pointer_mut(&pointer_to(&properties_pointer, name), f);
+            }
+            if let Some(AdditionalProperties::Schema(schema)) = additional_properties {
+                schema.visit_with_pointer_mut(&pointer_to(pointer, "additionalProperties"), f);
+            }
+            if let Some(schema) = items {
+                schema.visit_with_pointer_mut(&pointer_to(pointer, "items"), f);
+            }
+            let defs_pointer = pointer_to(pointer, "$defs");
+            for (name, schema) in defs.iter_mut().flatten() {
+                schema.visit_with_pointer_mut(&pointer_to(&defs_pointer, name), f);
+            }
+            for (key, schemas) in [("allOf", all_of), ("oneOf", one_of), ("anyOf", any_of)] {
+                let key_pointer = pointer_to(pointer, key);
+                for (i, schema) in schemas.iter_mut().flatten().enumerate() {
+                    schema.visit_with_pointer_mut(&pointer_to(&key_pointer, &i.to_string()), f);
+                }
+            }
+        }
+    }
+}
+
+impl OpenApi {
+    /// Read specifiaction from string in JSON or YAML format. The references
+    /// to other files cannot be followed without the path, use
+    /// [`OpenApi::from_file`] for such specifications.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(data: &str) -> Result<Self, Error> {
+        let mut spec: Self = resolving::parse_document(data)?;
+        spec.normalize();
+        Ok(spec)
+    }
+
+    /// Read specifiaction from file, the format is determined by the
+    /// extension (`json`, `yaml` or `yml`). The references to other files,
+    /// like `./common.yaml#/components/schemas/Money`, are followed, and the
+    /// referenced schemas are added to the components (if the name is
+    /// already taken, it is prefixed with the file name).
+    ///
+    /// Returns an error if a file cannot be read or a reference cannot be
+    /// resolved.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
         let path_ref = path.as_ref();
-        if path_ref.extension().unwrap() == "json" {
-            serde_json::from_reader(File::open(path_ref).unwrap()).unwrap()
-        } else if path_ref.extension().unwrap() == "yaml" {
-            serde_yaml::from_reader(File::open(path_ref).unwrap()).unwrap()
-        } else {
-            panic!("Couldn't determine the schema file format");
+        let mut spec: Self = resolving::read_document(path_ref)?;
+        spec.move_definitions();
+        resolving::resolve_external_refs(&mut spec, path_ref)?;
+        spec.normalize();
+        Ok(spec)
+    }
+
+    /// Returns all the schemas: the ones of the components (including the
+    /// shared parameters, request bodies and responses) and the ones of the
+    /// operations, along with their locations
+    pub(crate) fn schemas_mut(&mut self) -> Vec<(String, &mut Schema)> {
+        let Components {
+            schemas,
+            parameters,
+            request_bodies,
+            responses,
+        } = &mut self.components;
+        let mut all: Vec<_> = (sorted_entries_mut(schemas).into_iter())
+            .map(|(name, schema)| (pointer_to("#/components/schemas", name), schema))
+            .collect();
+        for (name, parameter) in sorted_entries_mut(parameters) {
+            let pointer = pointer_to("#/components/parameters", name);
+            if let Some(schema) = &mut parameter.schema {
+                all.push((pointer_to(&pointer, "schema"), schema));
+            }
+            all.extend(content_schemas_mut(&pointer, &mut parameter.content));
+        }
+        for (name, body) in sorted_entries_mut(request_bodies) {
+            let pointer = pointer_to("#/components/requestBodies", name);
+            all.extend(content_schemas_mut(&pointer, &mut body.content));
+        }
+        for (name, response) in sorted_entries_mut(responses) {
+            let pointer = pointer_to("#/components/responses", name);
+            if let Some(schema) = &mut response.schema {
+                all.push((pointer_to(&pointer, "schema"), schema));
+            }
+            all.extend(content_schemas_mut(&pointer, &mut response.content));
         }
+        for (path, item) in sorted_entries_mut(&mut self.paths) {
+            all.extend(item.schemas_mut(&pointer_to("#/paths", path)));
+        }
+        all
+    }
+
+    /// Brings the constructs of newer versions of the specification to the
+    /// form in which they were written in OpenAPI 3.0
+    fn normalize(&mut self) {
+        self.move_definitions();
+
+        // `{"const": "value"}` is the same as `{"enum": ["value"]}`
+        for (_, schema) in self.schemas_mut() {
+            schema.visit_mut(&mut |schema| {
+                if let Schema::Typed {
+                    enum_items,
+                    const_value: Some(value),
+                    ..
+                } = schema
+                {
+                    enum_items.get_or_insert_with(|| vec![value.clone()]);
+                }
+            });
+        }
+
+        self.hoist_defs();
+    }
+
+    /// Moves `definitions`, `parameters` and `responses` of Swagger 2.0 to
+    /// the components, so the references look like
+    /// `#/components/schemas/...`, `#/components/parameters/...` and
+    /// `#/components/responses/...`
+    fn move_definitions(&mut self) {
+        if self.definitions.is_empty() && self.parameters.is_empty() && self.responses.is_empty() {
+            return;
+        }
+        let components = &mut self.components;
+        components.schemas.extend(self.definitions.drain());
+        components.parameters.extend(self.parameters.drain());
+        components.responses.extend(self.responses.drain());
+
+        let rename = |ref_: &mut String| {
+            let sections = [
+                ("#/definitions/", "schemas"),
+                ("#/parameters/", "parameters"),
+                ("#/responses/", "responses"),
+            ];
+            for (prefix, section) in sections {
+                if let Some(name) = ref_.strip_prefix(prefix) {
+                    *ref_ = format!("#/components/{section}/{name}");
+                    return;
+                }
+            }
+        };
+        let parameter_refs = components.parameters.values_mut().map(|p| &mut p.ref_);
+        let response_refs = components.responses.values_mut().map(|r| &mut r.ref_);
+        let operation_refs = self.paths.values_mut().flat_map(|item| item.refs_mut());
+        for ref_ in parameter_refs
+            .chain(response_refs)
+            .flatten()
+            .chain(operation_refs)
+        {
+            rename(ref_);
+        }
+
+        for (_, schema) in self.schemas_mut() {
+            schema.visit_mut(&mut |schema| {
+                if let Schema::Ref { ref_ } = schema {
+                    rename(ref_);
+                }
+            });
+        }
+    }
+
+    /// Moves `$defs` of all schemas to the components, so they can be used
+    /// like any other schema
+    fn hoist_defs(&mut self) {
+        let schemas = &mut self.components.schemas;
+        // the old references (the exact pointers) and the new ones
+        let mut renames = HashMap::new();
+        // `#/$defs/...` refers to the definitions of the component schema it
+        // is written in, so these references are renamed for each component
+        let mut root_renames = HashMap::new();
+        // the component schemas that the hoisted definitions come from
+        let mut roots = HashMap::new();
+        let mut queue = vec![];
+
+        // sorting makes the generated names independent of the hash order
+        let mut names: Vec<_> = schemas.keys().cloned().collect();
+        names.sort();
+        for name in names.iter().rev() {
+            let pointer = pointer_to("#/components/schemas", name);
+            if let Some(schema) = schemas.get_mut(name) {
+                collect_defs(name, name, &pointer, schema, &mut queue);
+            }
+        }
+
+        while let Some(def) = queue.pop() {
+            let Def {
+                root,
+                owner,
+                pointer,
+                name,
+                mut schema,
+            } = def;
+            let new_name = unique_schema_name(schemas, &owner, &name);
+            collect_defs(&root, &new_name, &pointer, &mut schema, &mut queue);
+
+            let new_ref = format!("#/components/schemas/{new_name}");
+            let root_pointer = pointer_to("#/components/schemas", &root);
+            if pointer == pointer_to(&pointer_to(&root_pointer, "$defs"), &name) {
+                let relative = pointer_to("#/$defs", &name);
+                root_renames.insert((root.clone(), relative), new_ref.clone());
+            }
+            renames.insert(pointer, new_ref);
+            roots.insert(new_name.clone(), root);
+            schemas.insert(new_name, schema);
+        }
+
+        if renames.is_empty() {
+            return;
+        }
+        for (name, schema) in schemas.iter_mut() {
+            let root = roots.get(name).unwrap_or(name);
+            schema.visit_mut(&mut |schema| {
+                if let Schema::Ref { ref_ } = schema
+                    && let Some(new_ref) = root_renames.get(&(root.clone(), ref_.clone()))
+                {
+                    *ref_ = new_ref.clone();
+                }
+            });
+        }
+        // the schemas of the operations can refer to the definitions as well
+        for (_, schema) in self.schemas_mut() {
+            schema.visit_mut(&mut |schema| {
+                if let Schema::Ref { ref_ } = schema
+                    && let Some(new_ref) = renames.get(ref_)
+                {
+                    *ref_ = new_ref.clone();
+                }
+            });
+        }
+    }
+}
+
+/// A definition from `$defs` to be moved to the components
+struct Def {
+    /// Name of the component schema the definition comes from, possibly
+    /// through other definitions
+    root: String,
+    /// Name of the schema containing the definition, it qualifies the name
+    /// of the definition if the name is taken
+    owner: String,
+    /// Location of the definition
+    pointer: String,
+    name: String,
+    schema: Schema,
+}
+
+/// Takes all `$defs` out of the schema and its nested schemas, `pointer` is
+/// the location of the schema
+fn collect_defs(root: &str, owner: &str, pointer: &str, schema: &mut Schema, queue: &mut Vec<Def>) {
+    schema.visit_with_pointer_mut(pointer, &mut |pointer, schema| {
+        if let Schema::Typed { defs, .. } = schema {
+            let mut defs: Vec<_> = defs.take().into_iter().flatten().collect();
+            defs.sort_by(|a, b| b.0.cmp(&a.0));
+            let defs_pointer = pointer_to(pointer, "$defs");
+            for (name, schema) in defs {
+                queue.push(Def {
+                    root: root.to_owned(),
+                    owner: owner.to_owned(),
+                    pointer: pointer_to(&defs_pointer, &name),
+                    name,
+                    schema,
+                });
+            }
+        }
+    });
+}
+
+/// Returns `name` if there is no such schema yet, otherwise the name is
+/// prefixed with the name of the owner
+fn unique_schema_name(schemas: &HashMap<String, Schema>, owner: &str, name: &str) -> String {
+    if !schemas.contains_key(name) {
+        return name.to_owned();
+    }
+    let mut new_name = format!("{owner}_{name}");
+    let mut counter = 1;
+    while schemas.contains_key(&new_name) {
+        counter += 1;
+        new_name = format!("{owner}_{name}{counter}");
     }
+    new_name
 }
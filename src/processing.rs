@@ -1,24 +1,116 @@
-use crate::datatypes::{DataType, FieldType, StructField};
+use crate::datatypes::{DataType, FieldType, StructField, TaggedVariant, UnionVariant};
+use crate::error::Error;
 use crate::filter::FilterConfig;
-use crate::openapi::{OpenApi, Schema};
-use std::error::Error;
+use crate::openapi::{AdditionalProperties, Discriminator, OpenApi, Schema};
+use crate::selecting;
+use convert_case::{Case, Casing};
+use std::collections::HashMap;
 
-/// Performs schema parsing from the OpenAPI specification
+/// The state shared by the functions that process the schemas
+struct Context<'a> {
+    spec: &'a OpenApi,
+    filter: &'a FilterConfig,
+    /// The types of nested objects and enums of all the schemas
+    inline_types: Vec<DataType>,
+    /// The names of the structures by the names of their nested enums, the
+    /// same enum is shared only by the properties of one structure
+    inline_enum_owners: HashMap<String, String>,
+    /// The names of the nested types by the locations of their schemas, the
+    /// properties inherited through `allOf` reuse the types of the schema
+    /// they come from
+    inline_type_pointers: HashMap<String, String>,
+    /// The problems found so far, the processing goes on with the next
+    /// property or schema to report them all at once
+    errors: Vec<Error>,
+    /// The locations of the references and the names of the schemas they
+    /// refer to, to report the references to the schemas that are not
+    /// generated
+    references: Vec<(String, String)>,
+    /// The problems that do not prevent the generation
+    warnings: Vec<String>,
+}
+
+impl<'a> Context<'a> {
+    fn new(spec: &'a OpenApi, filter: &'a FilterConfig) -> Self {
+        Context {
+            spec,
+            filter,
+            inline_types: vec![],
+            inline_enum_owners: HashMap::new(),
+            inline_type_pointers: HashMap::new(),
+            errors: vec![],
+            references: vec![],
+            warnings: vec![],
+        }
+    }
+
+    /// Moves the error into the list of problems
+    fn collect_error<T>(&mut self, result: Result<T, Error>) -> Option<T> {
+        result.map_err(|e| self.errors.push(e)).ok()
+    }
+}
+
+/// The properties of an object, including those collected from the `allOf`
+/// members. The properties are accompanied by their locations in the
+/// specification.
+#[derive(Default)]
+struct ObjectSchema<'a> {
+    properties: Vec<(&'a String, &'a Schema, String)>,
+    required: Vec<&'a String>,
+    additional_properties: Option<(&'a AdditionalProperties, String)>,
+}
+
+/// Performs schema parsing from the OpenAPI specification. All the problems
+/// are collected, if there are several of them, [`Error::Multiple`] is
+/// returned. With `check_missing_schemas`, the references to the schemas
+/// that are not generated are reported too (see [`find_missing_schemas`]).
+/// The problems that do not prevent the generation, like the filter entries
+/// that have no effect, are added to `warnings`.
 pub fn process_components(
     spec: &OpenApi,
     filter: &FilterConfig,
-) -> Result<Vec<DataType>, Box<dyn Error>> {
+    check_missing_schemas: bool,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<DataType>, Error> {
+    // all the problems are collected to be reported at once
+    let mut errors = filter.check_patterns();
+    // the entries of the filter that do not match the specification; in the
+    // strict mode, they are reported along with the other problems
+    if filter.include.is_some() || filter.exclude.is_some() {
+        let invalid_entries = filter.find_invalid_entries(spec);
+        if filter.strict {
+            errors.extend(invalid_entries);
+        } else {
+            warnings.extend(invalid_entries.iter().map(|e| e.to_string()));
+        }
+    }
+    warnings.extend(filter.find_ineffective_entries(spec));
+
+    // the operations turn into the inclusions
+    let with_operations;
+    let filter = if !filter.has_operation_filter() {
+        filter
+    } else {
+        with_operations = selecting::include_operation_schemas(spec, filter, &mut errors);
+        &with_operations
+    };
+
     let mut dependencies = vec![];
     let mut datatypes = vec![];
+    let mut ctx = Context::new(spec, filter);
 
-    for (schema_name, definition) in spec.components.schemas.iter() {
+    // the order matters for choosing the name of the shared enums
+    let mut schemas: Vec<_> = spec.components.schemas.iter().collect();
+    schemas.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (schema_name, definition) in schemas {
         if !filter.is_schema_accepted(schema_name) {
             continue;
         }
-        let datatype = process_schema(schema_name, definition, filter)?;
-        datatypes.push(datatype);
+        let result = process_schema(schema_name, definition, &mut ctx);
+        datatypes.extend(ctx.collect_error(result));
         if filter.auto_include_dependencies {
-            find_dependend_schemas(&schema_name, spec, filter, &mut dependencies);
+            find_dependend_schemas(schema_name, spec, filter, &mut dependencies);
         }
     }
 
@@ -27,79 +119,678 @@ pub fn process_components(
             // already added
             continue;
         }
+        if filter.is_schema_excluded(&schema_name) {
+            // the exclusions apply to the dependencies as well
+            continue;
+        }
         if let Some(definition) = spec.components.schemas.get(&schema_name) {
-            datatypes.push(process_schema(&schema_name, definition, filter)?);
+            let result = process_schema(&schema_name, definition, &mut ctx);
+            datatypes.extend(ctx.collect_error(result));
         }
     }
 
+    datatypes.append(&mut ctx.inline_types);
+    remove_discriminator_fields(&mut datatypes);
+    box_recursive_fields(&mut datatypes);
+
+    errors.append(&mut ctx.errors);
+    for warning in ctx.warnings {
+        // the same schema may be processed more than once
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+    let missing_schemas = if check_missing_schemas {
+        find_missing_schemas(&datatypes)
+    } else {
+        vec![]
+    };
+    for name in missing_schemas {
+        let mut locations: Vec<_> = (ctx.references.iter())
+            .filter(|(_, n)| *n == name)
+            .map(|(location, _)| location.clone())
+            .collect();
+        if locations.is_empty() && !is_mapped_type(filter, &name) {
+            // the type did not come from a reference, the schemas using it
+            // are reported instead
+            locations = (datatypes.iter())
+                .filter(|dt| find_missing_types(dt, &datatypes).contains(&name))
+                .map(|dt| pointer_to("#/components/schemas", dt.schema_name()))
+                .collect();
+        }
+        for location in locations {
+            errors.push(Error::MissingSchema {
+                location,
+                name: name.clone(),
+            });
+        }
+    }
+    Error::from_list(errors)?;
     Ok(datatypes)
 }
 
-/// Performs parsing of a single schema from the OpenAPI specification
+/// Returns the names of the properties of the component schema that becomes
+/// a structure, including the ones of its `allOf` members and
+/// `additionalProperties` for the catch-all field
+pub(crate) fn schema_property_names(spec: &OpenApi, schema_name: &str) -> Vec<String> {
+    let Some(
+        definition @ Schema::Typed {
+            properties, all_of, ..
+        },
+    ) = spec.components.schemas.get(schema_name)
+    else {
+        return vec![];
+    };
+    if properties.is_none() && !is_composition(all_of) {
+        return vec![];
+    }
+    let pointer = pointer_to("#/components/schemas", schema_name);
+    let mut object = ObjectSchema::default();
+    // the problems are reported by the generation itself, the properties
+    // collected before them are still known
+    let _ = collect_object(&pointer, definition, spec, &mut object, &mut vec![]);
+    let mut names: Vec<_> = (object.properties.iter())
+        .map(|(name, ..)| name.to_string())
+        .collect();
+    // the catch-all field is selected by this name
+    if let Some((additional, _)) = &object.additional_properties
+        && additional.is_allowed()
+    {
+        names.push("additionalProperties".to_owned());
+    }
+    names
+}
+
+/// Returns the nested types (like `UserAddress`) generated for all the
+/// schemas regardless of the inclusions and the exclusions of the filter. The
+/// schemas that cannot be processed are skipped.
+pub(crate) fn process_inline_types(spec: &OpenApi, filter: &FilterConfig) -> Vec<DataType> {
+    let mut filter = filter.clone();
+    filter.include = None;
+    filter.exclude = None;
+    let mut ctx = Context::new(spec, &filter);
+    // the order matters for choosing the name of the shared enums
+    let mut schemas: Vec<_> = spec.components.schemas.iter().collect();
+    schemas.sort_by(|a, b| a.0.cmp(b.0));
+    for (schema_name, definition) in schemas {
+        let _ = process_schema(schema_name, definition, &mut ctx);
+    }
+    ctx.inline_types
+}
+
+/// A type that contains itself (directly or through other types) has an
+/// infinite size, so the fields closing such cycles are boxed. Arrays and maps
+/// already store their values on the heap, so they do not form cycles.
+fn box_recursive_fields(datatypes: &mut [DataType]) {
+    fn fields_mut(dt: &mut DataType) -> Vec<&mut StructField> {
+        match dt {
+            DataType::Struct { fields, .. } => fields.iter_mut().collect(),
+            DataType::Union { variants, .. } => variants.iter_mut().map(|v| &mut v.info).collect(),
+            DataType::Alias { info, .. } => vec![info],
+            DataType::Enum { .. } | DataType::IntegerEnum { .. } => vec![],
+        }
+    }
+
+    // depth-first search, the edges leading back to the types being visited
+    // close the cycles; `path` holds the edges from the first type to `node`
+    fn visit(
+        node: usize,
+        edges: &[Vec<(usize, Vec<usize>)>],
+        is_alias: &[bool],
+        visiting: &mut [Option<bool>],
+        path: &mut Vec<(usize, usize)>,
+        boxed: &mut Vec<(usize, usize)>,
+    ) {
+        visiting[node] = Some(true);
+        for (field, targets) in &edges[node] {
+            for &target in targets {
+                match visiting[target] {
+                    None => {
+                        path.push((node, *field));
+                        visit(target, edges, is_alias, visiting, path, boxed);
+                        path.pop();
+                    }
+                    Some(true) => {
+                        let start = path.iter().position(|(n, _)| *n == target);
+                        let mut cycle = path[start.unwrap_or(path.len())..].to_vec();
+                        cycle.push((node, *field));
+                        if cycle.iter().any(|edge| boxed.contains(edge)) {
+                            // already broken by another cycle
+                            continue;
+                        }
+                        // an alias is boxed only if the cycle consists of
+                        // aliases, otherwise its non-recursive uses would be
+                        // boxed too
+                        let edge = (cycle.iter().rev())
+                            .find(|(n, _)| !is_alias[*n])
+                            .copied()
+                            .unwrap_or((node, *field));
+                        boxed.push(edge);
+                    }
+                    Some(false) => (),
+                }
+            }
+        }
+        visiting[node] = Some(false);
+    }
+
+    let names: Vec<String> = datatypes
+        .iter()
+        .map(|dt| dt.schema_name().to_owned())
+        .collect();
+    let edges: Vec<Vec<(usize, Vec<usize>)>> = datatypes
+        .iter_mut()
+        .map(|dt| {
+            fields_mut(dt)
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| f.array_dimensions == 0)
+                .map(|(i, f)| {
+                    let targets = match &f.type_ {
+                        FieldType::Map(_) => vec![],
+                        t => t
+                            .to_vec()
+                            .iter()
+                            .filter_map(|t| names.iter().position(|n| n == t))
+                            .collect(),
+                    };
+                    (i, targets)
+                })
+                .collect()
+        })
+        .collect();
+
+    // the order of visiting determines which fields are boxed, so it should
+    // not depend on the order of the schemas in the specification
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    order.sort_by(|a, b| names[*a].cmp(&names[*b]));
+
+    let is_alias: Vec<bool> = (datatypes.iter())
+        .map(|dt| matches!(dt, DataType::Alias { .. }))
+        .collect();
+    let mut visiting = vec![None; names.len()];
+    let mut boxed = vec![];
+    for node in order {
+        if visiting[node].is_none() {
+            visit(
+                node,
+                &edges,
+                &is_alias,
+                &mut visiting,
+                &mut vec![],
+                &mut boxed,
+            );
+        }
+    }
+
+    for (node, field) in boxed {
+        fields_mut(&mut datatypes[node])[field].is_boxed = true;
+    }
+}
+
+/// The value of the discriminator property is written by the tagged enum
+/// itself, so the structures of its variants must not contain this property.
+/// The structures are kept as they are, since they can be used directly, and
+/// the enum uses their copies without the property (like
+/// `CatWithoutPetType`). The values of the property are taken from the
+/// discriminator mapping while the variants still refer to the original
+/// schemas, and the variants keep them, so they stay valid for the copies.
+fn remove_discriminator_fields(datatypes: &mut Vec<DataType>) {
+    /// Collects the types of the tagged variants with their tag properties
+    fn collect(field_type: &FieldType, tags: &mut Vec<(String, String)>) {
+        match field_type {
+            FieldType::Plain(_) | FieldType::OneOf(_) => (),
+            FieldType::Tagged { property, variants } => {
+                for variant in variants {
+                    tags.push((variant.type_name.clone(), property.clone()));
+                }
+            }
+            FieldType::Map(value) => collect(&value.type_, tags),
+        }
+    }
+
+    /// Replaces the types of the tagged variants with their copies
+    fn replace(field_type: &mut FieldType, copies: &[(String, String, String)]) {
+        match field_type {
+            FieldType::Plain(_) | FieldType::OneOf(_) => (),
+            FieldType::Tagged { property, variants } => {
+                for variant in variants {
+                    let t = &variant.type_name;
+                    if let Some((.., copy)) = copies.iter().find(|c| c.0 == *t && c.1 == *property)
+                    {
+                        variant.type_name = copy.clone();
+                    }
+                }
+            }
+            FieldType::Map(value) => replace(&mut value.type_, copies),
+        }
+    }
+
+    let mut tags = vec![];
+    for dt in datatypes.iter() {
+        match dt {
+            DataType::Struct { fields, .. } => {
+                for field in fields {
+                    collect(&field.type_, &mut tags);
+                }
+            }
+            DataType::Union {
+                tag: Some(tag),
+                variants,
+                ..
+            } => {
+                for variant in variants {
+                    match &variant.info.type_ {
+                        FieldType::Plain(t) => tags.push((t.clone(), tag.clone())),
+                        other => collect(other, &mut tags),
+                    }
+                }
+            }
+            DataType::Union { variants, .. } => {
+                for variant in variants {
+                    collect(&variant.info.type_, &mut tags);
+                }
+            }
+            DataType::Alias { info, .. } => collect(&info.type_, &mut tags),
+            DataType::Enum { .. } | DataType::IntegerEnum { .. } => (),
+        }
+    }
+    tags.sort();
+    tags.dedup();
+
+    let mut copies = vec![];
+    for (t, p) in tags {
+        let fields = datatypes.iter().find_map(|dt| match dt {
+            DataType::Struct { name, fields } if *name == t => Some(fields),
+            _ => None,
+        });
+        let Some(fields) = fields else {
+            continue;
+        };
+        if !fields.iter().any(|f| f.name == p) {
+            // nothing to remove, the structure can be used as is
+            continue;
+        }
+        let fields = fields.iter().filter(|f| f.name != p).cloned().collect();
+        let base_name = format!("{t}Without{}", p.to_case(Case::Pascal));
+        let mut copy = base_name.clone();
+        let mut counter = 1;
+        while datatypes.iter().any(|dt| dt.schema_name() == copy) {
+            counter += 1;
+            copy = format!("{base_name}{counter}");
+        }
+        datatypes.push(DataType::Struct {
+            name: copy.clone(),
+            fields,
+        });
+        copies.push((t, p, copy));
+    }
+
+    for dt in datatypes.iter_mut() {
+        match dt {
+            DataType::Struct { fields, .. } => {
+                for field in fields {
+                    replace(&mut field.type_, &copies);
+                }
+            }
+            DataType::Union { tag, variants, .. } => {
+                for variant in variants {
+                    match (&mut variant.info.type_, &tag) {
+                        (FieldType::Plain(t), Some(tag)) => {
+                            if let Some((.., copy)) =
+                                copies.iter().find(|c| c.0 == *t && c.1 == *tag)
+                            {
+                                *t = copy.clone();
+                            }
+                        }
+                        (other, _) => replace(other, &copies),
+                    }
+                }
+            }
+            DataType::Alias { info, .. } => replace(&mut info.type_, &copies),
+            DataType::Enum { .. } | DataType::IntegerEnum { .. } => (),
+        }
+    }
+}
+
+/// The message about a schema with both `oneOf` and `anyOf`, it is not
+/// clear which of them the value must match
+const ONE_OF_AND_ANY_OF: &str = "`oneOf` and `anyOf` cannot be used together";
+
+/// Performs parsing of a single schema from the OpenAPI specification. The
+/// types generated for the nested objects are added to `ctx.inline_types`.
 fn process_schema(
     schema_name: &str,
     definition: &Schema,
-    filter: &FilterConfig,
-) -> Result<DataType, Box<dyn Error>> {
+    ctx: &mut Context,
+) -> Result<DataType, Error> {
+    let pointer = pointer_to("#/components/schemas", schema_name);
     match definition {
         Schema::Ref { .. } => {
-            // An entry like `{"Schema": {"$ref": "#/..."}}` although it looks
-            // like the truth, it doesn't make sense - why not write the
-            // necessary structure right away? In general, I do not believe
-            // that we will be able to meet this.
-            let msg = format!("unexpected reference in root of {schema_name:?} definition");
-            Err(msg.into())
+            // An entry like `{"Schema": {"$ref": "#/..."}}` is usually a
+            // schema taken from another file, it becomes a type alias
+            Ok(DataType::Alias {
+                alias: schema_name.to_owned(),
+                info: process_schema_property(schema_name, "", &pointer, definition, true, ctx)?,
+            })
         }
         Schema::Typed {
             properties,
-            enum_items,
-            required,
+            all_of,
+            one_of,
+            any_of,
+            discriminator,
             ..
         } => {
+            if one_of.is_some() && any_of.is_some() {
+                return Err(Error::unsupported(&pointer, ONE_OF_AND_ANY_OF));
+            }
+            if let Some(members) = any_of
+                && ctx.filter.any_of_as_struct
+                && let Some(datatype) = process_any_of_as_struct(
+                    schema_name,
+                    schema_name,
+                    "",
+                    &pointer_to(&pointer, "anyOf"),
+                    members,
+                    ctx,
+                )?
+            {
+                return Ok(datatype);
+            }
+
             // if the root element is an object, then it must have properties
-            if let Some(props) = properties {
+            // (its own or from the `allOf` members)
+            if properties.is_some() || is_composition(all_of) {
                 // the object turns into a structure
-                let mut fields = vec![];
-                for (prop_name, prop_definition) in props {
-                    if !filter.is_property_accepted(schema_name, prop_name) {
-                        continue;
-                    }
-                    let field = process_schema_property(
+                let mut object = ObjectSchema::default();
+                collect_object(&pointer, definition, ctx.spec, &mut object, &mut vec![])?;
+                Ok(process_object(schema_name, &object, ctx, false))
+            } else if let Some(mut datatype) = process_enum(schema_name, &pointer, definition, ctx)
+            {
+                // this is an enum listing the options
+                if !definition.is_nullable() {
+                    return Ok(datatype);
+                }
+                // the enumeration cannot be null, as with the unions below
+                let type_name = inline_type_name(schema_name, "", &pointer, ctx)?;
+                if let DataType::Enum { name, .. } | DataType::IntegerEnum { name, .. } =
+                    &mut datatype
+                {
+                    *name = type_name.clone();
+                }
+                ctx.inline_types.push(datatype);
+                Ok(optional_alias(schema_name, type_name))
+            } else if let Some(members) = one_of.as_ref().or(any_of.as_ref())
+                && members.iter().filter(|s| !s.is_null()).count() > 1
+            {
+                // the value is one of the types
+                let keyword = if one_of.is_some() { "oneOf" } else { "anyOf" };
+                let members_pointer = pointer_to(&pointer, keyword);
+                if !definition.is_nullable() && members.iter().all(|s| !s.is_null()) {
+                    return process_union(
                         schema_name,
-                        &prop_name,
-                        prop_definition,
-                        required.contains(prop_name),
-                    )?;
-                    fields.push(field);
+                        schema_name,
+                        &members_pointer,
+                        members,
+                        discriminator.as_ref(),
+                        ctx,
+                    );
                 }
-                Ok(DataType::Struct {
-                    name: schema_name.to_owned(),
-                    fields,
-                })
-            } else if let Some(items) = enum_items {
-                // this is an enum listing the options
-                Ok(DataType::Enum {
-                    name: schema_name.to_owned(),
-                    items: items.clone(),
-                })
+                // the enumeration itself cannot be null, so it becomes a
+                // nested type, and the schema is an optional alias for it
+                let type_name = inline_type_name(schema_name, "", &pointer, ctx)?;
+                let datatype = process_union(
+                    &type_name,
+                    schema_name,
+                    &members_pointer,
+                    members,
+                    discriminator.as_ref(),
+                    ctx,
+                )?;
+                ctx.inline_types.push(datatype);
+                Ok(optional_alias(schema_name, type_name))
             } else {
                 // let's assume that this is a type alias
                 Ok(DataType::Alias {
                     alias: schema_name.to_owned(),
-                    info: process_schema_property(schema_name, "", definition, true)?,
+                    info: process_schema_property(
+                        schema_name,
+                        "",
+                        &pointer,
+                        definition,
+                        true,
+                        ctx,
+                    )?,
                 })
             }
         }
     }
 }
 
-/// Performs analysis of a single schema property
+/// Makes the schema an optional alias for the type generated in its place
+fn optional_alias(schema_name: &str, type_name: String) -> DataType {
+    DataType::Alias {
+        alias: schema_name.to_owned(),
+        info: StructField {
+            name: String::new(),
+            translated_name: String::new(),
+            type_: FieldType::Plain(type_name),
+            type_format: String::new(),
+            array_dimensions: 0,
+            is_nullable: true,
+            is_required: true,
+            is_flatten: false,
+            is_boxed: false,
+            descr: String::new(),
+        },
+    }
+}
+
+/// Turns an object into a structure. For nested objects (`is_inline`), the
+/// properties are filtered by the inclusions only if the generated name is
+/// listed in them. The properties that cannot be processed are skipped, their
+/// errors are collected in the context.
+fn process_object(
+    name: &str,
+    object: &ObjectSchema,
+    ctx: &mut Context,
+    is_inline: bool,
+) -> DataType {
+    let filter = ctx.filter;
+    let is_accepted = |prop_name: &str| {
+        if is_inline && !filter.is_schema_included(name) {
+            !filter.is_property_excluded(name, prop_name)
+        } else {
+            filter.is_property_accepted(name, prop_name)
+        }
+    };
+
+    // the order matters for choosing the name of the shared enums
+    let mut properties = object.properties.clone();
+    properties.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut fields = vec![];
+    for (prop_name, prop_definition, pointer) in properties {
+        if !is_accepted(prop_name) {
+            continue;
+        }
+        // the inherited properties get the nested types of the schema they
+        // come from, so these types are not duplicated
+        let owner = inherited_property_owner(&pointer, ctx.spec);
+        let result = process_schema_property(
+            owner.as_deref().unwrap_or(name),
+            prop_name,
+            &pointer,
+            prop_definition,
+            object.required.contains(&prop_name),
+            ctx,
+        );
+        fields.extend(ctx.collect_error(result));
+    }
+    // the rest of the properties are collected into a map, if they are
+    // allowed
+    if let Some((additional, pointer)) = &object.additional_properties
+        && additional.is_allowed()
+        && is_accepted("additionalProperties")
+    {
+        let result = process_additional_properties(
+            name,
+            "additionalProperties",
+            pointer,
+            additional,
+            true,
+            ctx,
+        );
+        if let Some(mut field) = ctx.collect_error(result) {
+            field.is_flatten = true;
+            // the flattened field has no name in the serialized object, so
+            // it is renamed if a property takes its name
+            let is_taken = |name: &str| {
+                let name = name.to_case(Case::Snake);
+                fields
+                    .iter()
+                    .any(|f: &StructField| f.translated_name.to_case(Case::Snake) == name)
+            };
+            let mut counter = 1;
+            while is_taken(&field.translated_name) {
+                counter += 1;
+                field.translated_name = format!("additional_properties_{counter}");
+            }
+            fields.push(field);
+        }
+    }
+    DataType::Struct {
+        name: name.to_owned(),
+        fields,
+    }
+}
+
+/// Returns the name of the component schema, if the property at `pointer` is
+/// its own property, like `#/components/schemas/Base/properties/kind`
+fn inherited_property_owner(pointer: &str, spec: &OpenApi) -> Option<String> {
+    let rest = pointer.strip_prefix("#/components/schemas/")?;
+    let (owner, property) = rest.split_once("/properties/")?;
+    if owner.contains('/') || property.contains('/') {
+        return None;
+    }
+    let owner = owner.replace("~1", "/").replace("~0", "~");
+    spec.components
+        .schemas
+        .contains_key(&owner)
+        .then_some(owner)
+}
+
+/// Collects the properties of an object, following the references and the
+/// `allOf` members. `visited` contains the names of the schemas being
+/// collected, to detect cyclic references.
+fn collect_object<'a>(
+    pointer: &str,
+    definition: &'a Schema,
+    spec: &'a OpenApi,
+    object: &mut ObjectSchema<'a>,
+    visited: &mut Vec<&'a str>,
+) -> Result<(), Error> {
+    match definition {
+        Schema::Ref { ref_ } => {
+            let ref_name = ref_schema_name(pointer, ref_)?;
+            if visited.contains(&ref_name) {
+                let msg = format!("cyclic reference to {ref_name:?} in `allOf`");
+                return Err(Error::unsupported(pointer, msg));
+            }
+            let Some(ref_definition) = spec.components.schemas.get(ref_name) else {
+                return Err(Error::UnresolvedReference {
+                    location: pointer.to_owned(),
+                    reference: ref_.clone(),
+                });
+            };
+            visited.push(ref_name);
+            // the properties are located in the referenced schema
+            collect_object(ref_, ref_definition, spec, object, visited)?;
+            visited.pop();
+            Ok(())
+        }
+        Schema::Typed {
+            schema_type,
+            properties,
+            additional_properties,
+            required,
+            items,
+            enum_items,
+            all_of,
+            one_of,
+            any_of,
+            ..
+        } => {
+            let is_object = schema_type.non_null().iter().all(|t| t == "object");
+            if !is_object
+                || items.is_some()
+                || enum_items.is_some()
+                || one_of.is_some()
+                || any_of.is_some()
+            {
+                let msg = "only objects can be combined in `allOf`";
+                return Err(Error::unsupported(pointer, msg));
+            }
+
+            for (prop_name, prop_definition) in properties.iter().flatten() {
+                let prop_pointer = pointer_to(&pointer_to(pointer, "properties"), prop_name);
+                match object.properties.iter().find(|(n, ..)| *n == prop_name) {
+                    // the same definition in several members is fine, even
+                    // if one of them adds a description
+                    Some((_, other, _)) if other.is_same_type(prop_definition) => (),
+                    Some(_) => {
+                        let msg =
+                            format!("conflicting definitions of property {prop_name:?} in `allOf`");
+                        return Err(Error::unsupported(&prop_pointer, msg));
+                    }
+                    None => object
+                        .properties
+                        .push((prop_name, prop_definition, prop_pointer)),
+                }
+            }
+            object.required.extend(required);
+            if let Some(additional) = additional_properties {
+                let additional_pointer = pointer_to(pointer, "additionalProperties");
+                match &object.additional_properties {
+                    Some((other, _)) if *other != additional => {
+                        let msg = "conflicting definitions of additionalProperties in `allOf`";
+                        return Err(Error::unsupported(&additional_pointer, msg));
+                    }
+                    _ => object.additional_properties = Some((additional, additional_pointer)),
+                }
+            }
+
+            for (i, member) in all_of.iter().flatten().enumerate() {
+                let member_pointer = pointer_to(&pointer_to(pointer, "allOf"), &i.to_string());
+                collect_object(&member_pointer, member, spec, object, visited)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns `true` if there are several `allOf` members to be merged. The
+/// members that only add a description or `nullable`, are not counted.
+fn is_composition(all_of: &Option<Vec<Schema>>) -> bool {
+    all_of
+        .iter()
+        .flatten()
+        .filter(|s| !s.is_annotation())
+        .count()
+        > 1
+}
+
+/// Performs analysis of a single schema property, `pointer` is its location
+/// in the specification
 fn process_schema_property(
     schema_name: &str,
     name: &str,
+    pointer: &str,
     definition: &Schema,
     is_required: bool,
-) -> Result<StructField, Box<dyn Error>> {
+    ctx: &mut Context,
+) -> Result<StructField, Error> {
     // trying to replace incorrect symbols for naming with their verbal
     // equivalent ("-" as "minus", "$" as "dollar", ...)
     let translated_name = translate_name(name);
@@ -108,103 +799,331 @@ fn process_schema_property(
         .chars()
         .all(|c| c.is_ascii_alphanumeric() || c == '_')
     {
-        let msg = format!("property {schema_name:?}.{name:?} has untranslatable name");
-        return Err(msg.into());
+        return Err(Error::UntranslatableName {
+            location: pointer.to_owned(),
+        });
     }
 
     match definition {
         Schema::Ref { ref_ } => {
-            return Ok(StructField {
+            let ref_name = ref_schema_name(pointer, ref_)?;
+            ctx.references
+                .push((pointer.to_owned(), ref_name.to_owned()));
+            Ok(StructField {
                 name: name.to_owned(),
                 translated_name,
-                type_: FieldType::Plain(
-                    ref_.strip_prefix("#/components/schemas/")
-                        .unwrap()
-                        .to_owned(),
-                ),
+                type_: FieldType::Plain(ref_name.to_owned()),
                 type_format: String::new(),
                 array_dimensions: 0,
-                is_nullable: !is_required,
+                is_nullable: false,
+                is_required,
+                is_flatten: false,
+                is_boxed: false,
                 descr: String::new(),
-            });
+            })
         }
         Schema::Typed {
             schema_type,
             format,
-            nullable,
             description,
             properties,
+            additional_properties,
             items,
-            enum_items: _,
             all_of,
             any_of,
             one_of,
+            discriminator,
             ..
         } => {
-            if let Some(_) = properties {
-                // "properties" is specified, which means it is an object. We
-                //  don't know how to generate nested objects.
-                let msg = format!(
-                    "property {schema_name:?}.{name:?} cannot be a nested object, use $ref instead"
-                );
-                Err(msg.into())
+            // OpenAPI 3.1 expresses nullability as `{"type": ["string",
+            // "null"]}` instead of `nullable: true`, and `null` can also be
+            // listed in `enum`
+            let nullable = definition.is_nullable();
+            if one_of.is_some() && any_of.is_some() {
+                return Err(Error::unsupported(pointer, ONE_OF_AND_ANY_OF));
+            }
+
+            if properties.is_some() || is_composition(all_of) {
+                // "properties" is specified (or the properties of the `allOf`
+                // members), which means it is a nested object, a separate
+                // structure is generated for it
+                let type_name = match ctx.inline_type_pointers.get(pointer) {
+                    Some(type_name) => type_name.clone(),
+                    None => {
+                        let type_name = inline_type_name(schema_name, name, pointer, ctx)?;
+                        let mut object = ObjectSchema::default();
+                        collect_object(pointer, definition, ctx.spec, &mut object, &mut vec![])?;
+                        ctx.inline_type_pointers
+                            .insert(pointer.to_owned(), type_name.clone());
+                        let datatype = process_object(&type_name, &object, ctx, true);
+                        ctx.inline_types.push(datatype);
+                        type_name
+                    }
+                };
+                Ok(StructField {
+                    name: name.to_owned(),
+                    translated_name,
+                    type_: FieldType::Plain(type_name),
+                    type_format: String::new(),
+                    array_dimensions: 0,
+                    is_nullable: nullable,
+                    is_required,
+                    is_flatten: false,
+                    is_boxed: false,
+                    descr: description.clone(),
+                })
+            } else if let Some(additional) = additional_properties
+                && additional.is_allowed()
+            {
+                // this is a dictionary-like object
+                let mut field = process_additional_properties(
+                    schema_name,
+                    name,
+                    &pointer_to(pointer, "additionalProperties"),
+                    additional,
+                    is_required,
+                    ctx,
+                )?;
+                field.is_nullable |= nullable;
+                field.descr = description.clone();
+                Ok(field)
             } else if let Some(items) = items {
                 // "items" is specified, this is an array
-                let mut field = process_schema_property(schema_name, name, items, is_required)?;
+                let items_pointer = pointer_to(pointer, "items");
+                let mut field = process_schema_property(
+                    schema_name,
+                    name,
+                    &items_pointer,
+                    items,
+                    is_required,
+                    ctx,
+                )?;
                 field.array_dimensions += 1;
                 // trying to account for nullable
-                field.is_nullable = field.is_nullable | *nullable;
+                field.is_nullable |= nullable;
                 // trying to fill the description
                 if field.descr.is_empty() && !description.is_empty() {
                     field.descr = description.clone();
                 }
                 Ok(field)
-            } else if let Some(schemas) = all_of {
-                if schemas.len() > 1 {
-                    // composition currently not supported
-                    return Err(
-                        format!("{schema_name:?}.{name:?}: expected one ref in `allOf`").into(),
-                    );
-                }
+            } else if let Some(schemas) = all_of
+                && let Some(i) = schemas.iter().position(|s| !s.is_annotation())
+            {
                 // behaves like a simple ref in this case
-                let mut field =
-                    process_schema_property(schema_name, name, &schemas[0], is_required)?;
-                // trying to account for nullable
-                field.is_nullable = field.is_nullable | *nullable;
+                let member_pointer = pointer_to(&pointer_to(pointer, "allOf"), &i.to_string());
+                let mut field = process_schema_property(
+                    schema_name,
+                    name,
+                    &member_pointer,
+                    &schemas[i],
+                    is_required,
+                    ctx,
+                )?;
+                // trying to account for nullable, including the members like
+                // `{"nullable": true}`
+                field.is_nullable |= nullable || schemas.iter().any(|s| s.is_nullable());
                 // trying to fill the description
                 if field.descr.is_empty() && !description.is_empty() {
                     field.descr = description.clone();
                 }
                 Ok(field)
-            } else if let Some(schemas) = one_of {
+            } else if let Some(members) = one_of.as_ref().or(any_of.as_ref()) {
+                // `anyOf` is handled like `oneOf`: the first matching type is
+                // taken during deserialization.
+                // OpenAPI 3.1 expresses nullability as a `{"type": "null"}`
+                // branch, it is not a separate type
+                let keyword = if one_of.is_some() { "oneOf" } else { "anyOf" };
+                let members_pointer = pointer_to(pointer, keyword);
+                // the members are accompanied by their locations
+                let schemas: Vec<_> = members
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, s)| !s.is_null())
+                    .map(|(i, s)| (pointer_to(&members_pointer, &i.to_string()), s))
+                    .collect();
+                let nullable = nullable || schemas.len() < members.len();
+                if let [(member_pointer, schema)] = &schemas[..] {
+                    // behaves like a simple ref in this case
+                    let mut field = process_schema_property(
+                        schema_name,
+                        name,
+                        member_pointer,
+                        schema,
+                        is_required,
+                        ctx,
+                    )?;
+                    field.is_nullable |= nullable;
+                    if field.descr.is_empty() && !description.is_empty() {
+                        field.descr = description.clone();
+                    }
+                    return Ok(field);
+                }
+                if one_of.is_none() && ctx.filter.any_of_as_struct {
+                    let type_name = inline_type_name(schema_name, name, pointer, ctx)?;
+                    if let Some(datatype) = process_any_of_as_struct(
+                        &type_name,
+                        schema_name,
+                        name,
+                        &members_pointer,
+                        members,
+                        ctx,
+                    )? {
+                        ctx.inline_types.push(datatype);
+                        return Ok(StructField {
+                            name: name.to_owned(),
+                            translated_name,
+                            type_: FieldType::Plain(type_name),
+                            type_format: String::new(),
+                            array_dimensions: 0,
+                            is_nullable: nullable,
+                            is_required,
+                            is_flatten: false,
+                            is_boxed: false,
+                            descr: description.clone(),
+                        });
+                    }
+                }
                 // field can have one of the specified types
-                let mut types = vec![];
-                for schema in schemas {
-                    let field = process_schema_property(schema_name, name, schema, is_required)?;
-                    types.extend(field.type_.to_vec());
+                let mut fields = vec![];
+                for (i, (member_pointer, schema)) in schemas.into_iter().enumerate() {
+                    // the index distinguishes the names of nested objects
+                    let field = process_schema_property(
+                        schema_name,
+                        &format!("{name}{}", i + 1),
+                        &member_pointer,
+                        schema,
+                        is_required,
+                        ctx,
+                    )?;
+                    // the tag is a property of an object, as in `process_union`
+                    let member_type = field.type_.to_vec().join("Or");
+                    if discriminator.is_some()
+                        && (is_primitive_type(&member_type) || field.array_dimensions > 0)
+                    {
+                        let msg = format!("type {member_type:?} cannot be used with discriminator");
+                        return Err(Error::unsupported(&member_pointer, msg));
+                    }
+                    fields.push(field);
+                }
+                // the arrays, the maps and the formats cannot be expressed by
+                // the names of the types, so such members become the variants
+                // of a separate enumeration, like the `oneOf` at the root
+                let is_plain = |f: &StructField| {
+                    matches!(f.type_, FieldType::Plain(_))
+                        && f.array_dimensions == 0
+                        && f.type_format.is_empty()
+                };
+                if discriminator.is_none() && !fields.iter().all(is_plain) {
+                    let type_name = match ctx.inline_type_pointers.get(pointer) {
+                        Some(type_name) => type_name.clone(),
+                        None => {
+                            let type_name = inline_type_name(schema_name, name, pointer, ctx)?;
+                            let mut variants = vec![];
+                            for (i, mut info) in fields.into_iter().enumerate() {
+                                info.is_required = true;
+                                let variant =
+                                    union_variant(schema_name, i, info, None, &variants, ctx);
+                                variants.push(variant);
+                            }
+                            ctx.inline_type_pointers
+                                .insert(pointer.to_owned(), type_name.clone());
+                            ctx.inline_types.push(DataType::Union {
+                                name: type_name.clone(),
+                                tag: None,
+                                variants,
+                            });
+                            type_name
+                        }
+                    };
+                    return Ok(StructField {
+                        name: name.to_owned(),
+                        translated_name,
+                        type_: FieldType::Plain(type_name),
+                        type_format: String::new(),
+                        array_dimensions: 0,
+                        is_nullable: nullable,
+                        is_required,
+                        is_flatten: false,
+                        is_boxed: false,
+                        descr: description.clone(),
+                    });
                 }
+                let types: Vec<_> = fields.iter().flat_map(|f| f.type_.to_vec()).collect();
+                let type_ = match discriminator {
+                    Some(discriminator) => {
+                        // the value of the property determines the type
+                        let variants = types
+                            .into_iter()
+                            .map(|t| TaggedVariant {
+                                tag_value: discriminator.value_for(&t),
+                                name: t.clone(),
+                                type_name: t,
+                            })
+                            .collect();
+                        FieldType::Tagged {
+                            property: discriminator.property_name.clone(),
+                            variants,
+                        }
+                    }
+                    None => FieldType::OneOf(types),
+                };
+                Ok(StructField {
+                    name: name.to_owned(),
+                    translated_name,
+                    type_,
+                    type_format: String::new(),
+                    array_dimensions: 0,
+                    is_nullable: nullable,
+                    is_required,
+                    is_flatten: false,
+                    is_boxed: false,
+                    descr: description.clone(),
+                })
+            } else if let Some(datatype) = process_enum("", pointer, definition, ctx) {
+                // an enumeration right in the property, a separate type is
+                // generated for it
+                let type_name = add_inline_enum(datatype, schema_name, name, pointer, ctx)?;
                 Ok(StructField {
                     name: name.to_owned(),
                     translated_name,
-                    type_: FieldType::OneOf(types),
+                    type_: FieldType::Plain(type_name),
                     type_format: String::new(),
                     array_dimensions: 0,
-                    is_nullable: *nullable,
+                    is_nullable: nullable,
+                    is_required,
+                    is_flatten: false,
+                    is_boxed: false,
                     descr: description.clone(),
                 })
-            } else if let Some(_) = any_of {
-                Err(format!("{schema_name:?}.{name:?}: `anyOf` is not supported").into())
-            } else if !schema_type.is_empty() {
+            } else if !schema_type.non_null().is_empty() {
                 // in this case, it's a primitive type
-                return Ok(StructField {
+                let mut types = schema_type.non_null();
+                // an unknown type, like `file` of Swagger 2, is reported as a
+                // missing schema, unless the configuration maps it
+                for t in types.iter().filter(|t| !is_primitive_type(t)) {
+                    if !is_mapped_type(ctx.filter, t) {
+                        ctx.references.push((pointer.to_owned(), t.clone()));
+                    }
+                }
+                let type_ = if types.len() == 1 {
+                    FieldType::Plain(types.remove(0))
+                } else {
+                    // OpenAPI 3.1 allows several types, like `["string",
+                    // "integer"]`
+                    FieldType::OneOf(types)
+                };
+                Ok(StructField {
                     name: name.to_owned(),
                     translated_name,
-                    type_: FieldType::Plain(schema_type.clone()),
+                    type_,
                     type_format: format.clone(),
-                    is_nullable: *nullable | !is_required,
+                    is_nullable: nullable,
+                    is_required,
+                    is_flatten: false,
+                    is_boxed: false,
                     descr: description.clone(),
                     array_dimensions: 0,
-                });
+                })
             } else {
                 // nothing is specified, not even type - believe that the field can be any object
                 Ok(StructField {
@@ -213,7 +1132,10 @@ fn process_schema_property(
                     type_: FieldType::Plain("object".into()),
                     type_format: String::new(),
                     array_dimensions: 0,
-                    is_nullable: *nullable | !is_required,
+                    is_nullable: nullable,
+                    is_required,
+                    is_flatten: false,
+                    is_boxed: false,
                     descr: description.clone(),
                 })
             }
@@ -221,6 +1143,458 @@ fn process_schema_property(
     }
 }
 
+/// Turns `oneOf` or `anyOf` at the root of the schema into an enumeration
+/// `type_name`, whose variants are named after the types of their values.
+/// The `null` members are skipped, `pointer` is the location of the members
+/// list.
+fn process_union(
+    type_name: &str,
+    schema_name: &str,
+    pointer: &str,
+    members: &[Schema],
+    discriminator: Option<&Discriminator>,
+    ctx: &mut Context,
+) -> Result<DataType, Error> {
+    let mut variants: Vec<UnionVariant> = vec![];
+    for (i, member) in members.iter().enumerate().filter(|(_, s)| !s.is_null()) {
+        let member_pointer = pointer_to(pointer, &i.to_string());
+        // the index distinguishes the names of nested objects
+        let info = process_schema_property(
+            schema_name,
+            &format!("{}", i + 1),
+            &member_pointer,
+            member,
+            true,
+            ctx,
+        )?;
+        let member_type = info.type_.to_vec().join("Or");
+        if discriminator.is_some() && (is_primitive_type(&member_type) || info.array_dimensions > 0)
+        {
+            let msg = format!("type {member_type:?} cannot be used with discriminator");
+            return Err(Error::unsupported(&member_pointer, msg));
+        }
+        let variant = union_variant(schema_name, i, info, discriminator, &variants, ctx);
+        variants.push(variant);
+    }
+    Ok(DataType::Union {
+        name: type_name.to_owned(),
+        tag: discriminator.map(|d| d.property_name.clone()),
+        variants,
+    })
+}
+
+/// Makes a variant of the enumeration for the member with the index `i`, the
+/// variant is named after the type of its value, or by the name from the
+/// filter for `schema_name`
+fn union_variant(
+    schema_name: &str,
+    i: usize,
+    info: StructField,
+    discriminator: Option<&Discriminator>,
+    variants: &[UnionVariant],
+    ctx: &Context,
+) -> UnionVariant {
+    let type_name = info.type_.to_vec().join("Or");
+    let mut name = type_name.to_case(Case::Pascal);
+    if matches!(info.type_, FieldType::Map(_)) {
+        name += "Map";
+    }
+    for _ in 0..info.array_dimensions {
+        name += "List";
+    }
+    if let Some(custom_name) = ctx
+        .filter
+        .union_variant_names
+        .get(&format!("{schema_name}.{name}"))
+    {
+        name = custom_name.clone();
+    }
+    // the same types with different formats, like int32 and int64
+    if variants.iter().any(|v| v.name == name) {
+        name = format!("{name}{}", i + 1);
+    }
+
+    let tag_value = match discriminator {
+        Some(discriminator) => discriminator.value_for(&type_name),
+        None => String::new(),
+    };
+    UnionVariant {
+        name,
+        tag_value,
+        info,
+    }
+}
+
+/// Represents `anyOf` as a structure with an optional flattened field for
+/// each member, so that all the matching members are deserialized. Returns
+/// [`None`] if some members are not objects. The `null` members are skipped,
+/// `pointer` is the location of the members list.
+fn process_any_of_as_struct(
+    type_name: &str,
+    schema_name: &str,
+    name: &str,
+    pointer: &str,
+    members: &[Schema],
+    ctx: &mut Context,
+) -> Result<Option<DataType>, Error> {
+    let members: Vec<_> = members
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| !s.is_null())
+        .collect();
+    if !members.iter().all(|(_, m)| is_object(m, ctx.spec)) {
+        return Ok(None);
+    }
+    let mut fields = vec![];
+    for (i, (member_index, member)) in members.into_iter().enumerate() {
+        // the index distinguishes the names of nested objects
+        let mut field = process_schema_property(
+            schema_name,
+            &format!("{name}{}", i + 1),
+            &pointer_to(pointer, &member_index.to_string()),
+            member,
+            false,
+            ctx,
+        )?;
+        // the field is named after its type
+        field.name = field.type_.to_vec().join("Or");
+        field.translated_name = translate_name(&field.name);
+        field.is_flatten = true;
+        fields.push(field);
+    }
+    Ok(Some(DataType::Struct {
+        name: type_name.to_owned(),
+        fields,
+    }))
+}
+
+/// Returns `true` if the schema (or the schema it refers to) is an object
+/// with properties
+fn is_object(definition: &Schema, spec: &OpenApi) -> bool {
+    let definition = match definition {
+        Schema::Ref { ref_ } => match ref_
+            .strip_prefix("#/components/schemas/")
+            .and_then(|name| spec.components.schemas.get(name))
+        {
+            Some(definition) => definition,
+            None => return false,
+        },
+        Schema::Typed { .. } => definition,
+    };
+    match definition {
+        Schema::Ref { .. } => false,
+        Schema::Typed {
+            properties, all_of, ..
+        } => properties.is_some() || is_composition(all_of),
+    }
+}
+
+/// Performs analysis of "additionalProperties" as a map from property names
+/// to values, `pointer` is the location of "additionalProperties"
+fn process_additional_properties(
+    schema_name: &str,
+    name: &str,
+    pointer: &str,
+    additional: &AdditionalProperties,
+    is_required: bool,
+    ctx: &mut Context,
+) -> Result<StructField, Error> {
+    let value = match additional {
+        AdditionalProperties::Schema(definition) => {
+            process_schema_property(schema_name, name, pointer, definition, true, ctx)?
+        }
+        // values can be anything
+        AdditionalProperties::Allowed(_) => StructField {
+            name: name.to_owned(),
+            translated_name: translate_name(name),
+            type_: FieldType::Plain("object".into()),
+            type_format: String::new(),
+            array_dimensions: 0,
+            is_nullable: false,
+            is_required: true,
+            is_flatten: false,
+            is_boxed: false,
+            descr: String::new(),
+        },
+    };
+    Ok(StructField {
+        name: name.to_owned(),
+        translated_name: translate_name(name),
+        type_: FieldType::Map(Box::new(value)),
+        type_format: String::new(),
+        array_dimensions: 0,
+        is_nullable: false,
+        is_required,
+        is_flatten: false,
+        is_boxed: false,
+        descr: String::new(),
+    })
+}
+
+/// Makes up a name for the structure of a nested object: the name of the
+/// schema and the name of the property (`OrderShippingAddress` for
+/// `Order.shippingAddress`), or `Item` instead of the property at the root
+/// of the schema. The name can be overridden in the filter. If the name is
+/// taken by a schema or by another nested type, a number is appended to it,
+/// and the name from the filter is an error.
+fn inline_type_name(
+    schema_name: &str,
+    name: &str,
+    pointer: &str,
+    ctx: &Context,
+) -> Result<String, Error> {
+    let is_taken = |type_name: &str| {
+        let type_name = type_name.to_case(Case::Pascal);
+        let schemas = ctx.spec.components.schemas.keys().map(|s| s.as_str());
+        let inline_types = ctx.inline_types.iter().map(|dt| dt.schema_name());
+        schemas
+            .chain(inline_types)
+            .any(|s| s.to_case(Case::Pascal) == type_name)
+    };
+
+    let path = format!("{schema_name}.{name}");
+    if let Some(type_name) = ctx.filter.inline_type_names.get(&path) {
+        if is_taken(type_name) {
+            let message = format!(
+                "the type name {type_name:?} for {path:?} from `inline_type_names` is already taken"
+            );
+            return Err(Error::unsupported(pointer, message));
+        }
+        return Ok(type_name.clone());
+    }
+    let type_name = if name.is_empty() {
+        format!("{schema_name}Item")
+    } else {
+        format!(
+            "{schema_name}{}",
+            translate_name(name).to_case(Case::Pascal)
+        )
+    };
+    let mut new_name = type_name.clone();
+    let mut counter = 1;
+    while is_taken(&new_name) {
+        counter += 1;
+        new_name = format!("{type_name}{counter}");
+    }
+    Ok(new_name)
+}
+
+/// Adds an enumeration of the property `name` to `inline_types` and returns
+/// its name. If the same enumeration has already been added for another
+/// property of the structure `schema_name`, it is used instead, so the types
+/// of one structure do not depend on the others, including its nested
+/// objects. The enumerations of different structures are only shared if they
+/// are given the same name in `inline_type_names`.
+fn add_inline_enum(
+    mut datatype: DataType,
+    schema_name: &str,
+    name: &str,
+    pointer: &str,
+    ctx: &mut Context,
+) -> Result<String, Error> {
+    fn sorted<T: Clone + Ord>(items: &[T]) -> Vec<T> {
+        let mut items = items.to_vec();
+        items.sort();
+        items
+    }
+
+    let path = format!("{schema_name}.{name}");
+    let type_name = ctx.filter.inline_type_names.get(&path);
+    for dt in &ctx.inline_types {
+        let is_shared = match type_name {
+            Some(type_name) => dt.schema_name() == type_name,
+            None => {
+                ctx.inline_enum_owners
+                    .get(dt.schema_name())
+                    .map(String::as_str)
+                    == Some(schema_name)
+            }
+        };
+        let is_same = is_shared
+            && match (dt, &datatype) {
+                (DataType::Enum { items: a, .. }, DataType::Enum { items: b, .. }) => {
+                    sorted(a) == sorted(b)
+                }
+                (
+                    DataType::IntegerEnum { items: a, .. },
+                    DataType::IntegerEnum { items: b, .. },
+                ) => sorted(a) == sorted(b),
+                _ => false,
+            };
+        if is_same {
+            return Ok(dt.schema_name().to_owned());
+        }
+    }
+    let type_name = inline_type_name(schema_name, name, pointer, ctx)?;
+    if let DataType::Enum { name, .. } | DataType::IntegerEnum { name, .. } = &mut datatype {
+        *name = type_name.clone();
+    }
+    ctx.inline_enum_owners
+        .insert(type_name.clone(), schema_name.to_owned());
+    ctx.inline_types.push(datatype);
+    Ok(type_name)
+}
+
+/// Turns `enum` into an enumeration of strings or integers. Other values
+/// (booleans, floating point numbers, mixed types) cannot be listed in a Rust
+/// enum, so such schemas are handled as the primitive types. The strings and
+/// the integers that cannot be listed are reported as warnings, `pointer` is
+/// the location of the schema.
+fn process_enum(
+    name: &str,
+    pointer: &str,
+    definition: &Schema,
+    ctx: &mut Context,
+) -> Option<DataType> {
+    let Schema::Typed {
+        schema_type,
+        enum_items: Some(items),
+        enum_names,
+        ..
+    } = definition
+    else {
+        return None;
+    };
+    // `null` makes the value optional, it is not an item
+    let items: Vec<_> = items
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !value.is_null())
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+    let types = schema_type.non_null();
+
+    // the names are listed in the same order as the values, they may be
+    // anything, so only the valid ones are taken
+    let listed_name = |i: usize| {
+        let name = enum_names.as_ref()?.get(i)?;
+        variant_name(name)
+    };
+
+    if types.iter().all(|t| t == "string")
+        && let Some(values) = items
+            .iter()
+            .map(|(i, value)| Some((*i, value.as_str()?)))
+            .collect::<Option<Vec<_>>>()
+    {
+        let mut items: Vec<(String, String)> = vec![];
+        for (i, value) in values {
+            // the values like "10" are named like the integers, and the
+            // values without any letters or digits after their positions
+            let base_name = listed_name(i)
+                .or_else(|| variant_name(value))
+                .or_else(|| variant_name(&format!("Value{value}")))
+                .unwrap_or_else(|| format!("Value{i}"));
+            let name = unique_variant_name(&base_name, items.iter().map(|(n, _)| n));
+            items.push((name, value.to_owned()));
+        }
+        return Some(DataType::Enum {
+            name: name.to_owned(),
+            items,
+        });
+    }
+
+    if types.iter().all(|t| t == "integer")
+        && let Some(values) = items
+            .iter()
+            .map(|(i, value)| Some((*i, value.as_i64()?)))
+            .collect::<Option<Vec<_>>>()
+    {
+        let mut items: Vec<(String, i64)> = vec![];
+        for (i, value) in values {
+            let default_name = if value < 0 {
+                format!("ValueMinus{}", value.unsigned_abs())
+            } else {
+                format!("Value{value}")
+            };
+            let base_name = listed_name(i).unwrap_or(default_name);
+            let name = unique_variant_name(&base_name, items.iter().map(|(n, _)| n));
+            items.push((name, value));
+        }
+        return Some(DataType::IntegerEnum {
+            name: name.to_owned(),
+            items,
+        });
+    }
+
+    // the type promises an enumeration, but some values do not fit it
+    if let [type_] = types.as_slice()
+        && (type_ == "string" || type_ == "integer")
+    {
+        ctx.warnings.push(format!(
+            "{pointer}: the values of the enum are not all of type {type_:?}, \
+                it is read as the type itself"
+        ));
+    }
+    None
+}
+
+/// Turns the value or the name from `x-enum-varnames` into the name of
+/// a variant, or returns [`None`] if it is not a valid identifier
+fn variant_name(value: &str) -> Option<String> {
+    let is_valid = |name: &str| {
+        name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && name != "Self"
+    };
+    // the symbols are spelled out only if the name cannot be made without
+    // them, so that "in-progress" stays `InProgress`
+    let name = value.to_case(Case::Pascal);
+    if is_valid(&name) {
+        return Some(name);
+    }
+    let name = translate_name(value).to_case(Case::Pascal);
+    if is_valid(&name) {
+        return Some(name);
+    }
+    // the other symbols (like "." or "/") and the non-ASCII letters separate
+    // the words, as "_" does
+    let sanitized: String = translate_name(value)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = sanitized.to_case(Case::Pascal);
+    is_valid(&name).then_some(name)
+}
+
+/// Appends a number to the name of a variant, if it is already taken
+fn unique_variant_name<'a>(
+    base_name: &str,
+    taken: impl Iterator<Item = &'a String> + Clone,
+) -> String {
+    let mut name = base_name.to_owned();
+    let mut counter = 1;
+    while taken.clone().any(|taken| *taken == name) {
+        counter += 1;
+        name = format!("{base_name}{counter}");
+    }
+    name
+}
+
+/// Returns the name of the schema to which `ref_` refers, `pointer` is the
+/// location of the reference
+fn ref_schema_name<'a>(pointer: &str, ref_: &'a str) -> Result<&'a str, Error> {
+    match ref_.strip_prefix("#/components/schemas/") {
+        Some(ref_name) if !ref_name.contains('/') => Ok(ref_name),
+        // references to other files are resolved by `OpenApi::from_file`,
+        // there should be no others
+        _ => Err(Error::UnresolvedReference {
+            location: pointer.to_owned(),
+            reference: ref_.to_owned(),
+        }),
+    }
+}
+
+/// Appends the segment to the JSON pointer, escaping `~` and `/`
+pub(crate) fn pointer_to(pointer: &str, segment: &str) -> String {
+    format!(
+        "{pointer}/{}",
+        segment.replace('~', "~0").replace('/', "~1")
+    )
+}
+
 /// Using a filter, not all the necessary structures can be generated, which
 /// is what we are trying to understand in order to avoid compilation
 /// errors
@@ -232,44 +1606,38 @@ fn process_schema_property(
 /// error, so to simplify debugging, we immediately intercept such
 /// situations.
 pub fn find_missing_schemas(datatypes: &[DataType]) -> Vec<String> {
-    let mut missing_schemas = vec![];
-
-    for dt in datatypes {
-        match dt {
-            DataType::Enum { .. } => (),
-            DataType::Struct { fields, .. } => {
-                for field in fields {
-                    for t in field.type_.to_vec() {
-                        // not looking for primitive types, they are always there
-                        if !is_primitive_type(&t) {
-                            // trying to find the field type in the datatypes list
-                            if !datatypes.iter().any(|dt| dt.schema_name() == t) {
-                                // didn't find it, it means an error
-                                missing_schemas.push(t.clone());
-                            }
-                        }
-                    }
-                }
-            }
-            DataType::Alias { info, .. } => {
-                // not looking for primitive types, they are always there
-                for t in info.type_.to_vec() {
-                    if !is_primitive_type(&t) {
-                        // trying to find the field type in the datatypes list
-                        if !datatypes.iter().any(|dt| dt.schema_name() == t) {
-                            // didn't find it, it means an error
-                            missing_schemas.push(t.clone());
-                        }
-                    }
-                }
-            }
-        }
-    }
+    let mut missing_schemas: Vec<_> = datatypes
+        .iter()
+        .flat_map(|dt| find_missing_types(dt, datatypes))
+        .collect();
 
+    // the same schema can be referenced by several types
+    missing_schemas.sort();
     missing_schemas.dedup();
     missing_schemas
 }
 
+/// Returns the types used by the data type that are neither primitive nor
+/// present in `datatypes`
+fn find_missing_types(dt: &DataType, datatypes: &[DataType]) -> Vec<String> {
+    let types = match dt {
+        DataType::Enum { .. } | DataType::IntegerEnum { .. } => vec![],
+        DataType::Struct { fields, .. } => fields.iter().flat_map(|f| f.type_.to_vec()).collect(),
+        DataType::Union { variants, .. } => (variants.iter())
+            .flat_map(|v| v.info.type_.to_vec())
+            .collect(),
+        DataType::Alias { info, .. } => info.type_.to_vec(),
+    };
+    types
+        .into_iter()
+        // not looking for primitive types, they are always there
+        .filter(|t| !is_primitive_type(t))
+        // trying to find the type in the datatypes list, if it is not there,
+        // it means an error
+        .filter(|t| !datatypes.iter().any(|dt| dt.schema_name() == t))
+        .collect()
+}
+
 fn is_primitive_type(typename: &str) -> bool {
     matches!(
         typename,
@@ -277,15 +1645,25 @@ fn is_primitive_type(typename: &str) -> bool {
     )
 }
 
+/// Returns `true` if the configuration maps the OpenAPI type, like `file`,
+/// to a Rust type, so it is not a missing schema
+fn is_mapped_type(filter: &FilterConfig, typename: &str) -> bool {
+    (filter.type_mappings.iter()).any(|m| m.type_.as_deref() == Some(typename))
+}
+
 /// Populates a [`Vec`] of dependent schemas via recursion
-fn find_dependend_schemas(
+pub(crate) fn find_dependend_schemas(
     schema_name: &str,
     spec: &OpenApi,
     filter: &FilterConfig,
     dependencies: &mut Vec<String>,
 ) {
-    if let Some(definition) = spec.components.schemas.get(schema_name) {
-        if let Ok(dt) = process_schema(schema_name, definition, filter) {
+    let mut ctx = Context::new(spec, filter);
+    if let Some(definition) = spec.components.schemas.get(schema_name)
+        && let Ok(dt) = process_schema(schema_name, definition, &mut ctx)
+    {
+        // the nested objects may refer to other schemas too
+        for dt in std::iter::once(dt).chain(ctx.inline_types) {
             match dt {
                 DataType::Struct { fields, .. } => {
                     for field in fields {
@@ -300,6 +1678,19 @@ fn find_dependend_schemas(
                         }
                     }
                 }
+                DataType::Union { variants, .. } => {
+                    for variant in variants {
+                        for t in variant.info.type_.to_vec() {
+                            if is_primitive_type(&t) {
+                                continue;
+                            }
+                            if !dependencies.contains(&t) {
+                                dependencies.push(t.clone());
+                                find_dependend_schemas(&t, spec, filter, dependencies);
+                            }
+                        }
+                    }
+                }
                 DataType::Alias { info, .. } => {
                     for t in info.type_.to_vec() {
                         if is_primitive_type(&t) {
@@ -312,7 +1703,7 @@ fn find_dependend_schemas(
                     }
                 }
                 // refs in enums are not possible
-                DataType::Enum { .. } => (),
+                DataType::Enum { .. } | DataType::IntegerEnum { .. } => (),
             }
         }
     }
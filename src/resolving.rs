@@ -0,0 +1,214 @@
+use crate::error::Error;
+use crate::openapi::{OpenApi, Schema};
+use crate::processing::pointer_to;
+use convert_case::{Case, Casing};
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Follows the references to other files (like
+/// `./common.yaml#/components/schemas/Money`) in all the schemas of the
+/// specification, including the ones of the operations, and adds the
+/// referenced schemas to the components, the references themselves are
+/// replaced with local ones
+pub fn resolve_external_refs(spec: &mut OpenApi, root: &Path) -> Result<(), Error> {
+    let root = root.canonicalize().map_err(|source| Error::Io {
+        path: root.to_owned(),
+        source,
+    })?;
+    let mut resolver = Resolver {
+        taken_names: spec
+            .components
+            .schemas
+            .keys()
+            .map(|name| name.to_case(Case::Pascal))
+            .collect(),
+        documents: HashMap::new(),
+        resolved: HashMap::new(),
+        loaded: vec![],
+        root: root.clone(),
+    };
+
+    // the schemas are sorted, so the generated names do not depend on the
+    // hash order
+    for (pointer, schema) in spec.schemas_mut() {
+        resolver.resolve_refs(schema, &pointer, &root)?;
+    }
+
+    // the loaded schemas may refer to other files too, they are reported at
+    // their locations among the components
+    while let Some((document, name, mut schema)) = resolver.loaded.pop() {
+        let pointer = pointer_to("#/components/schemas", &name);
+        resolver.resolve_refs(&mut schema, &pointer, &document)?;
+        spec.components.schemas.insert(name, schema);
+    }
+
+    Ok(())
+}
+
+struct Resolver {
+    /// Names of schemas that are already used, in the Pascal case of the
+    /// type names, so `common_Money` and `CommonMoney` are the same name
+    taken_names: HashSet<String>,
+    /// Loaded files by canonical path
+    documents: HashMap<PathBuf, serde_json::Value>,
+    /// Names of schemas by the file and JSON pointer that refer to them. It
+    /// also allows to stop on cyclic references.
+    resolved: HashMap<(PathBuf, String), String>,
+    /// Schemas whose references have not been resolved yet: (file, name,
+    /// schema)
+    loaded: Vec<(PathBuf, String, Schema)>,
+    /// The root file of the specification
+    root: PathBuf,
+}
+
+impl Resolver {
+    /// Replaces all the references in `schema`, located in `document`, with
+    /// the local ones, `pointer` is the location of the schema
+    fn resolve_refs(
+        &mut self,
+        schema: &mut Schema,
+        pointer: &str,
+        document: &Path,
+    ) -> Result<(), Error> {
+        let mut result = Ok(());
+        schema.visit_with_pointer_mut(pointer, &mut |location, schema| {
+            if let Schema::Ref { ref_ } = schema
+                && result.is_ok()
+            {
+                match self.resolve_ref(ref_, location, document) {
+                    Ok(Some(new_ref)) => *ref_ = new_ref,
+                    Ok(None) => (),
+                    Err(e) => result = Err(e),
+                }
+            }
+        });
+        result
+    }
+
+    /// Returns a local reference for `ref_`, or [`None`] if it is already
+    /// local, `location` is the location of the referencing schema
+    fn resolve_ref(
+        &mut self,
+        ref_: &str,
+        location: &str,
+        document: &Path,
+    ) -> Result<Option<String>, Error> {
+        let (file, pointer) = ref_.split_once('#').unwrap_or((ref_, ""));
+        if file.is_empty() && document == self.root {
+            // a reference inside the root file
+            return Ok(None);
+        }
+
+        let unresolved = || Error::UnresolvedReference {
+            location: location.to_owned(),
+            reference: ref_.to_owned(),
+        };
+        let target = if file.is_empty() {
+            document.to_owned()
+        } else {
+            let dir = document.parent().unwrap_or(Path::new(""));
+            dir.join(file).canonicalize().map_err(|_| unresolved())?
+        };
+
+        if target == self.root {
+            // a reference from another file back to the root one
+            let local_name = pointer
+                .strip_prefix("/components/schemas/")
+                .or_else(|| pointer.strip_prefix("/definitions/"));
+            if let Some(name) = local_name
+                && !name.contains('/')
+            {
+                return Ok(Some(format!("#/components/schemas/{name}")));
+            }
+        }
+
+        let key = (target.clone(), pointer.to_owned());
+        if let Some(name) = self.resolved.get(&key) {
+            return Ok(Some(format!("#/components/schemas/{name}")));
+        }
+
+        if !self.documents.contains_key(&target) {
+            let value = read_document(&target)?;
+            self.documents.insert(target.clone(), value);
+        }
+        let value = self.documents[&target]
+            .pointer(pointer)
+            .ok_or_else(unresolved)?;
+        let mut schema: Schema =
+            serde_json::from_value(value.clone()).map_err(|source| Error::Parse {
+                path: Some(target.clone()),
+                source: source.into(),
+            })?;
+        // the definitions are taken directly from the file if someone refers
+        // to them
+        if let Schema::Typed { defs, .. } = &mut schema {
+            *defs = None;
+        }
+
+        let name = self.unique_name(&target, pointer);
+        self.resolved.insert(key, name.clone());
+        self.loaded.push((target, name.clone(), schema));
+        Ok(Some(format!("#/components/schemas/{name}")))
+    }
+
+    /// Makes up a name for the schema from another file: the last part of the
+    /// pointer, and if it is already used, then prefixed with the file name
+    fn unique_name(&mut self, document: &Path, pointer: &str) -> String {
+        let file_stem = document
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let name = match pointer.rsplit('/').next() {
+            Some(name) if !name.is_empty() => name.replace("~1", "/").replace("~0", "~"),
+            _ => file_stem.clone(),
+        };
+
+        let mut new_name = name.clone();
+        let mut counter = 1;
+        while self.taken_names.contains(&new_name.to_case(Case::Pascal)) {
+            counter += 1;
+            new_name = if counter == 2 {
+                format!("{file_stem}_{name}")
+            } else {
+                format!("{file_stem}_{name}{counter}")
+            };
+        }
+        self.taken_names.insert(new_name.to_case(Case::Pascal));
+        new_name
+    }
+}
+
+/// Reads a JSON or YAML file, the format is determined by the extension
+pub(crate) fn read_document<T: DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let parse_error = |source| Error::Parse {
+        path: Some(path.to_owned()),
+        source,
+    };
+    let format = path.extension().and_then(|e| e.to_str());
+    if !matches!(format, Some("json" | "yaml" | "yml")) {
+        return Err(Error::UnknownFileFormat {
+            path: path.to_owned(),
+        });
+    }
+    let file = File::open(path).map_err(|source| Error::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    match format {
+        Some("json") => serde_json::from_reader(file).map_err(|e| parse_error(e.into())),
+        _ => serde_yaml::from_reader(file).map_err(|e| parse_error(e.into())),
+    }
+}
+
+/// Reads a JSON or YAML document from a string, JSON is recognized by the
+/// opening brace
+pub(crate) fn parse_document<T: DeserializeOwned>(data: &str) -> Result<T, Error> {
+    let parse_error = |source| Error::Parse { path: None, source };
+    if data.trim_start().starts_with('{') {
+        serde_json::from_str(data).map_err(|e| parse_error(e.into()))
+    } else {
+        serde_yaml::from_str(data).map_err(|e| parse_error(e.into()))
+    }
+}
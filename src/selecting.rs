@@ -0,0 +1,239 @@
+use crate::error::Error;
+use crate::filter::{FilterConfig, SchemaFilter};
+use crate::openapi::{MediaType, OpenApi, Operation, PathItem, Schema};
+use crate::processing::{self, pointer_to};
+use std::collections::HashMap;
+
+/// Adds the schemas used by the operations listed in `include_operations` or
+/// selected by their tags to the inclusions of the filter, along with all
+/// the schemas they depend on. The schemas already present in the
+/// inclusions keep their filters. The problems, like the unknown
+/// operations, are added to `errors`, and the schemas of the other
+/// operations are still included.
+pub fn include_operation_schemas(
+    spec: &OpenApi,
+    filter: &FilterConfig,
+    errors: &mut Vec<Error>,
+) -> FilterConfig {
+    let mut operations = vec![];
+    for operation in &filter.include_operations {
+        // the method is case-insensitive
+        let expected = match operation.split_once(' ') {
+            Some((method, path)) => format!("{} {}", method.to_uppercase(), path.trim()),
+            None => operation.clone(),
+        };
+        let found = find_operations(spec, |id, method_path| {
+            id == Some(operation) || method_path == expected
+        });
+        if found.is_empty() {
+            errors.push(Error::UnknownOperation {
+                operation: operation.clone(),
+            });
+        }
+        operations.extend(found);
+    }
+    if !filter.include_tags.is_empty() || !filter.exclude_tags.is_empty() {
+        for found in find_operations(spec, |_, _| true) {
+            if filter.is_tag_accepted(&found.2.tags)
+                && !operations.iter().any(|(pointer, ..)| *pointer == found.0)
+            {
+                operations.push(found);
+            }
+        }
+    }
+
+    let mut schema_names = vec![];
+    for (pointer, item, operation) in operations {
+        for (pointer, schema) in operation_schemas(spec, &pointer, item, operation, errors) {
+            collect_schema_names(spec, &pointer, schema, &mut schema_names, errors);
+        }
+    }
+
+    // the dependencies are found the same way as with
+    // `auto_include_dependencies`
+    let mut dependencies = vec![];
+    let mut dependency_filter = filter.clone();
+    dependency_filter.auto_include_dependencies = true;
+    for schema_name in &schema_names {
+        processing::find_dependend_schemas(
+            schema_name,
+            spec,
+            &dependency_filter,
+            &mut dependencies,
+        );
+    }
+
+    let mut new_filter = filter.clone();
+    let include = new_filter.include.get_or_insert_with(HashMap::new);
+    for schema_name in schema_names.into_iter().chain(dependencies) {
+        // the schemas listed by their names or by the patterns keep their
+        // filters
+        if !filter.is_schema_included(&schema_name) {
+            include.insert(schema_name, SchemaFilter::AcceptAll("*".into()));
+        }
+    }
+    new_filter
+}
+
+/// Finds the operations for which `is_matched` returns `true` given their
+/// `operationId` and the method with the path, like `"GET /pets/{id}"`.
+/// Returns them with their locations and the path items containing them.
+fn find_operations(
+    spec: &OpenApi,
+    is_matched: impl Fn(Option<&str>, &str) -> bool,
+) -> Vec<(String, &PathItem, &Operation)> {
+    let mut paths: Vec<_> = spec.paths.iter().collect();
+    paths.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut found = vec![];
+    for (path, item) in paths {
+        for (method, candidate) in item.operations() {
+            let method_path = format!("{method} {path}");
+            if is_matched(candidate.operation_id.as_deref(), &method_path) {
+                let pointer = pointer_to(&pointer_to("#/paths", path), &method.to_lowercase());
+                found.push((pointer, item, candidate));
+            }
+        }
+    }
+    found
+}
+
+/// Returns the schemas of the parameters, the request body and the
+/// responses of the operation with their locations. The references to the
+/// parameters, the request bodies and the responses of the components are
+/// followed.
+fn operation_schemas<'a>(
+    spec: &'a OpenApi,
+    pointer: &str,
+    item: &'a PathItem,
+    operation: &'a Operation,
+    errors: &mut Vec<Error>,
+) -> Vec<(String, &'a Schema)> {
+    let components = &spec.components;
+    let mut schemas = vec![];
+    let mut contents = vec![];
+
+    // the parameters of the path are shared by its operations
+    let item_pointer = pointer.rsplit_once('/').map_or(pointer, |(p, _)| p);
+    let parameters = (item.parameters.iter().enumerate())
+        .map(|(i, p)| (item_pointer, i, p))
+        .chain((operation.parameters.iter().enumerate()).map(|(i, p)| (pointer, i, p)));
+    for (owner, i, parameter) in parameters {
+        let pointer = pointer_to(&pointer_to(owner, "parameters"), &i.to_string());
+        let ref_ = parameter.ref_.as_deref();
+        let section = &components.parameters;
+        if let Some((pointer, parameter)) =
+            follow_ref(pointer, parameter, ref_, section, "parameters", errors)
+        {
+            if let Some(schema) = &parameter.schema {
+                schemas.push((pointer_to(&pointer, "schema"), schema));
+            }
+            contents.push((pointer, &parameter.content));
+        }
+    }
+
+    if let Some(body) = &operation.request_body {
+        let pointer = pointer_to(pointer, "requestBody");
+        let ref_ = body.ref_.as_deref();
+        let section = &components.request_bodies;
+        if let Some((pointer, body)) =
+            follow_ref(pointer, body, ref_, section, "requestBodies", errors)
+        {
+            contents.push((pointer, &body.content));
+        }
+    }
+
+    let mut responses: Vec<_> = operation.responses.iter().collect();
+    responses.sort_by(|a, b| a.0.cmp(b.0));
+    for (status, response) in responses {
+        let pointer = pointer_to(&pointer_to(pointer, "responses"), status);
+        let ref_ = response.ref_.as_deref();
+        let section = &components.responses;
+        if let Some((pointer, response)) =
+            follow_ref(pointer, response, ref_, section, "responses", errors)
+        {
+            if let Some(schema) = &response.schema {
+                schemas.push((pointer_to(&pointer, "schema"), schema));
+            }
+            contents.push((pointer, &response.content));
+        }
+    }
+
+    for (pointer, content) in contents {
+        schemas.extend(content_schemas(&pointer, content));
+    }
+    schemas
+}
+
+/// Returns the schemas of all the media types, sorted by the media type
+fn content_schemas<'a>(
+    pointer: &str,
+    content: &'a HashMap<String, MediaType>,
+) -> Vec<(String, &'a Schema)> {
+    let mut media_types: Vec<_> = content.iter().collect();
+    media_types.sort_by(|a, b| a.0.cmp(b.0));
+    media_types
+        .into_iter()
+        .filter_map(|(media_type, m)| {
+            let pointer = pointer_to(&pointer_to(pointer, "content"), media_type);
+            Some((pointer_to(&pointer, "schema"), m.schema.as_ref()?))
+        })
+        .collect()
+}
+
+/// Returns the item itself, or the one from the section of the components
+/// if it is a reference like `#/components/responses/NotFound`, along with
+/// its location
+fn follow_ref<'a, T>(
+    pointer: String,
+    item: &'a T,
+    ref_: Option<&str>,
+    section: &'a HashMap<String, T>,
+    section_name: &str,
+    errors: &mut Vec<Error>,
+) -> Option<(String, &'a T)> {
+    let Some(ref_) = ref_ else {
+        return Some((pointer, item));
+    };
+    let prefix = format!("#/components/{section_name}/");
+    match ref_
+        .strip_prefix(&prefix)
+        .and_then(|name| section.get(name))
+    {
+        Some(item) => Some((ref_.to_owned(), item)),
+        None => {
+            errors.push(Error::UnresolvedReference {
+                location: pointer,
+                reference: ref_.to_owned(),
+            });
+            None
+        }
+    }
+}
+
+/// Collects the names of the schemas referenced by the schema or its nested
+/// schemas
+fn collect_schema_names(
+    spec: &OpenApi,
+    pointer: &str,
+    schema: &Schema,
+    schema_names: &mut Vec<String>,
+    errors: &mut Vec<Error>,
+) {
+    schema.visit(&mut |schema| {
+        let Schema::Ref { ref_ } = schema else {
+            return;
+        };
+        match ref_.strip_prefix("#/components/schemas/") {
+            Some(name) if spec.components.schemas.contains_key(name) => {
+                if !schema_names.iter().any(|n| n == name) {
+                    schema_names.push(name.to_owned());
+                }
+            }
+            _ => errors.push(Error::UnresolvedReference {
+                location: pointer.to_owned(),
+                reference: ref_.clone(),
+            }),
+        }
+    });
+}
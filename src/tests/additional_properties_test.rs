@@ -0,0 +1,129 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Item:
+      type: object
+      properties:
+        id:
+          type: integer
+    ItemsById:
+      type: object
+      additionalProperties:
+        $ref: '#/components/schemas/Item'
+    Labels:
+      type: object
+      additionalProperties: true
+    Order:
+      type: object
+      required: [id, counters]
+      properties:
+        id:
+          type: integer
+        counters:
+          type: object
+          additionalProperties:
+            type: array
+            items:
+              type: integer
+              format: int64
+      additionalProperties:
+        type: string
+"##;
+
+#[test]
+fn test_additional_properties() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub type ItemsById = std::collections::HashMap<String, Item>;"));
+    assert!(s.contains("pub type Labels = std::collections::HashMap<String, serde_json::Value>;"));
+    assert!(s.contains(
+        r#"pub struct Order {
+    pub counters: std::collections::HashMap<String, Vec<i64>>,
+    pub id: i32,
+    #[serde(flatten)]
+    pub additional_properties: std::collections::HashMap<String, String>,
+}"#
+    ));
+}
+
+#[test]
+fn test_additional_properties_filtered() {
+    let filter = r#"
+include:
+  Order: [id]
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the catch-all map is selected like any other property
+    assert!(s.contains("pub struct Order {\n    pub id: i32,\n}"));
+}
+
+#[test]
+fn test_additional_properties_name_taken() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Settings:
+      type: object
+      required: [additional_properties]
+      properties:
+        additional_properties:
+          type: boolean
+      additionalProperties:
+        type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains(
+        r#"pub struct Settings {
+    pub additional_properties: bool,
+    #[serde(flatten)]
+    pub additional_properties_2: std::collections::HashMap<String, String>,
+}"#
+    ));
+}
+
+#[test]
+fn test_additional_properties_in_one_of() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Setting:
+      type: object
+      required: [value]
+      properties:
+        value:
+          oneOf:
+            - type: object
+              additionalProperties:
+                type: string
+            - type: string
+            - type: array
+              items:
+                type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the map and the array are kept in the variants
+    assert!(s.contains("pub value: SettingValue,"));
+    assert!(s.contains(
+        r#"#[serde(untagged)]
+pub enum SettingValue {
+    StringMap(std::collections::HashMap<String, String>),
+    String(String),
+    StringList(Vec<String>),
+}"#
+    ));
+    assert!(!s.contains("_Union"));
+}
@@ -0,0 +1,208 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Base:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: integer
+          format: int64
+    Named:
+      type: object
+      properties:
+        name:
+          type: string
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/Base'
+        - $ref: '#/components/schemas/Named'
+        - type: object
+          required: [name]
+          properties:
+            tag:
+              type: string
+    Owner:
+      type: object
+      properties:
+        pet:
+          allOf:
+            - $ref: '#/components/schemas/Pet'
+            - nullable: true
+        address:
+          allOf:
+            - $ref: '#/components/schemas/Base'
+            - properties:
+                city:
+                  type: string
+"##;
+
+#[test]
+fn test_allof_merge() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Pet {
+    pub id: i64,
+    pub name: String,
+    pub tag: Option<String>,
+}"#
+    ));
+    assert!(s.contains(
+        r#"pub struct Owner {
+    pub address: Option<OwnerAddress>,
+    pub pet: Option<Pet>,
+}"#
+    ));
+    assert!(s.contains(
+        r#"pub struct OwnerAddress {
+    pub city: Option<String>,
+    pub id: i64,
+}"#
+    ));
+}
+
+#[test]
+fn test_allof_required_only() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Named:
+      type: object
+      properties:
+        name:
+          type: string
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/Named'
+        - required: [name]
+    Owner:
+      type: object
+      properties:
+        named:
+          allOf:
+            - $ref: '#/components/schemas/Named'
+            - required: [name]
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the member with only `required` is merged too
+    assert!(s.contains("pub struct Pet {\n    pub name: String,\n}"));
+    assert!(s.contains("pub named: Option<OwnerNamed>,"));
+    assert!(s.contains("pub struct OwnerNamed {\n    pub name: String,\n}"));
+    assert!(s.contains("pub struct Named {\n    pub name: Option<String>,\n}"));
+}
+
+#[test]
+fn test_allof_conflict() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Base:
+      type: object
+      properties:
+        id:
+          type: integer
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/Base'
+        - type: object
+          properties:
+            id:
+              type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("conflicting definitions of property \"id\"")
+    );
+
+    // a member that only adds the annotations refines the property
+    const REFINED: &str = r##"
+components:
+  schemas:
+    Base:
+      type: object
+      properties:
+        id:
+          type: string
+        tags:
+          type: array
+          items:
+            type: string
+    Pet:
+      allOf:
+        - $ref: '#/components/schemas/Base'
+        - type: object
+          required: [id]
+          properties:
+            id:
+              type: string
+              description: Identifier of the pet
+              example: pet-1
+            tags:
+              type: array
+              items:
+                type: string
+                description: Tag name
+"##;
+    let openapi = OpenApi::from_str(REFINED).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct Pet {"));
+    assert!(s.contains("    pub id: String,\n"));
+    assert!(s.contains("    pub tags: Option<Vec<String>>,\n"));
+}
+
+#[test]
+fn test_allof_inherited_inline_types() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Base:
+      type: object
+      properties:
+        kind:
+          type: string
+          enum: [a, b]
+        address:
+          type: object
+          properties:
+            city:
+              type: string
+    Animal:
+      allOf:
+        - $ref: '#/components/schemas/Base'
+        - type: object
+          properties:
+            name:
+              type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the types of the inherited properties are the ones of the base schema
+    assert!(s.contains(
+        r#"pub struct Animal {
+    pub address: Option<BaseAddress>,
+    pub kind: Option<BaseKind>,
+    pub name: Option<String>,
+}"#
+    ));
+    assert_eq!(s.matches("pub enum BaseKind {").count(), 1);
+    assert_eq!(s.matches("pub struct BaseAddress {").count(), 1);
+    assert!(!s.contains("AnimalKind"));
+    assert!(!s.contains("AnimalAddress"));
+}
@@ -16,9 +16,9 @@ components:
           $ref: '#/components/schemas/AnyObject'
 "##;
 
-    let openapi = OpenApi::from_str(SCHEMA);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
     let config = FilterConfig::default();
-    let types = processing::process_components(&openapi, &config).unwrap();
+    let types = processing::process_components(&openapi, &config, false, &mut vec![]).unwrap();
 
     for dt in types {
         match dt.schema_name() {
@@ -63,9 +63,9 @@ components:
           type: object
 "##;
 
-    let openapi = OpenApi::from_str(SCHEMA);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
     let config = FilterConfig::default();
-    let types = processing::process_components(&openapi, &config).unwrap();
+    let types = processing::process_components(&openapi, &config, false, &mut vec![]).unwrap();
 
     for dt in types {
         match dt.schema_name() {
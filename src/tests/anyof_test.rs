@@ -0,0 +1,118 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        lives:
+          type: integer
+    Dog:
+      type: object
+      properties:
+        bark:
+          type: string
+    Pet:
+      anyOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+    Owner:
+      type: object
+      required: [id]
+      properties:
+        id:
+          anyOf:
+            - type: string
+            - type: integer
+        pet:
+          anyOf:
+            - $ref: '#/components/schemas/Cat'
+            - $ref: '#/components/schemas/Dog'
+            - type: "null"
+"##;
+
+#[test]
+fn test_anyof_union() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"#[serde(untagged)]
+pub enum Pet {
+    Cat(Cat),
+    Dog(Dog),
+}"#
+    ));
+    assert!(s.contains("pub id: _UnionStringOrInteger,"));
+    assert!(s.contains("pub pet: Option<_UnionCatOrDog>,"));
+    assert!(s.contains(
+        r#"#[serde(untagged)]
+pub enum _UnionStringOrInteger {
+    String(String),
+    Integer(i32),
+}"#
+    ));
+}
+
+#[test]
+fn test_anyof_as_struct() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("any_of_as_struct: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Pet {
+    #[serde(flatten)]
+    pub cat: Option<Cat>,
+    #[serde(flatten)]
+    pub dog: Option<Dog>,
+}"#
+    ));
+    // the members are not objects
+    assert!(s.contains("pub id: _UnionStringOrInteger,"));
+    assert!(s.contains("pub pet: Option<OwnerPet>,"));
+    assert!(s.contains("pub struct OwnerPet {"));
+}
+
+#[test]
+fn test_anyof_with_oneof() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - type: string
+        - type: integer
+      anyOf:
+        - type: string
+    Owner:
+      type: object
+      properties:
+        id:
+          oneOf:
+            - type: string
+            - type: integer
+          anyOf:
+            - type: integer
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let locations: Vec<_> = err
+        .iter()
+        .map(|e| match e {
+            Error::Unsupported { location, .. } => location.as_str(),
+            _ => panic!("unexpected error: {e}"),
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        [
+            "#/components/schemas/Owner/properties/id",
+            "#/components/schemas/Pet",
+        ]
+    );
+}
@@ -37,10 +37,10 @@ include:
     - someString
 auto_include_dependencies: true
 "#;
-    let openapi = OpenApi::from_str(SCHEMA);
-    let config = FilterConfig::from_str(filter);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
 
-    let structs = processing::process_components(&openapi, &config).unwrap();
+    let structs = processing::process_components(&openapi, &config, false, &mut vec![]).unwrap();
     assert!(processing::find_missing_schemas(&structs).is_empty());
 
     // check "Big" struct
@@ -77,10 +77,10 @@ include:
   Small:
     - someString
 "#;
-    let openapi = OpenApi::from_str(SCHEMA);
-    let config = FilterConfig::from_str(filter);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
 
-    let structs = processing::process_components(&openapi, &config).unwrap();
+    let structs = processing::process_components(&openapi, &config, false, &mut vec![]).unwrap();
     // struct "Medium" is not present and not included in filter, so it's missing
     assert!(
         processing::find_missing_schemas(&structs)
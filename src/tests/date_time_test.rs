@@ -0,0 +1,277 @@
+use super::fixtures::{assert_generated, date_shapes, date_time_shapes, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Event:
+      type: object
+      required: [at, clock, history]
+      properties:
+        at:
+          type: string
+          format: date-time
+        day:
+          type: string
+          format: date
+        clock:
+          type: string
+          format: time
+        lasts:
+          type: string
+          format: duration
+        history:
+          type: array
+          items:
+            type: string
+            format: date-time
+        reminders:
+          type: array
+          items:
+            type: string
+            format: date-time
+"##;
+
+#[test]
+fn test_time_backend() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Event {
+    #[serde(with = "time::serde::rfc3339")]
+    pub at: time::OffsetDateTime,
+    #[serde(with = "_time_serde_time")]
+    pub clock: time::Time,
+    #[serde(with = "_time_serde_date_option", default)]
+    pub day: Option<time::Date>,
+    #[serde(with = "_time_serde_rfc3339_vec")]
+    pub history: Vec<time::OffsetDateTime>,
+    pub lasts: Option<String>,
+    #[serde(with = "_time_serde_rfc3339_option_vec", default)]
+    pub reminders: Option<Vec<time::OffsetDateTime>>,
+}"#
+    ));
+    assert!(s.contains("mod _time_serde_time {"));
+    // the time is written without fractions and offset, like "17:32:28"
+    assert!(s.contains(r#"value.format(format_description!("[hour]:[minute]:[second]"))"#));
+    assert!(s.contains(
+        r#"let format = format_description!("[hour]:[minute]:[second][optional [.[subsecond]]]");"#
+    ));
+    assert!(!s.contains("Iso8601"));
+    assert!(s.contains(
+        r#"mod _time_serde_rfc3339_option_vec {
+    struct Ser<'a>(&'a time::OffsetDateTime);
+"#
+    ));
+    assert!(s.contains(
+        r#"    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<time::OffsetDateTime>>, D::Error> {
+        let value: Option<Vec<De>> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|v| v.into_iter().map(|v| v.0).collect()))
+    }"#
+    ));
+    assert!(s.contains(
+        "        serde::Serialize::serialize(&value.iter().map(Ser).collect::<Vec<_>>(), serializer)\n"
+    ));
+}
+
+#[test]
+fn test_time_types_of_0_2() {
+    // the mappings from the README, which restore the generated code of 0.2
+    let filter = r#"
+type_mappings:
+  - { type: string, format: date, rust_type: time::Date }
+  - type: string
+    format: date-time
+    rust_type: time::OffsetDateTime
+    with: time::serde::iso8601
+    option_with: time::serde::iso8601::option
+  - { type: string, format: time, rust_type: String }
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"    #[serde(with = "time::serde::iso8601")]
+    pub at: time::OffsetDateTime,
+    pub clock: String,
+    pub day: Option<time::Date>,
+"#
+    ));
+    assert!(!s.contains("mod _time_serde_time"));
+    assert!(!s.contains("mod _time_serde_date"));
+}
+
+#[test]
+fn test_chrono_backend() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("date_time_backend: chrono").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the types are read by themselves
+    assert!(s.contains(
+        r#"pub struct Event {
+    pub at: chrono::DateTime<chrono::FixedOffset>,
+    pub clock: chrono::NaiveTime,
+    pub day: Option<chrono::NaiveDate>,
+    pub history: Vec<chrono::DateTime<chrono::FixedOffset>>,
+    pub lasts: Option<String>,
+    pub reminders: Option<Vec<chrono::DateTime<chrono::FixedOffset>>>,
+}"#
+    ));
+    assert!(!s.contains("mod "));
+}
+
+#[test]
+fn test_jiff_backend() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("date_time_backend: jiff").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Event {
+    pub at: jiff::Timestamp,
+    pub clock: jiff::civil::Time,
+    pub day: Option<jiff::civil::Date>,
+    pub history: Vec<jiff::Timestamp>,
+    pub lasts: Option<jiff::Span>,
+    pub reminders: Option<Vec<jiff::Timestamp>>,
+}"#
+    ));
+    assert!(!s.contains("mod "));
+}
+
+#[test]
+fn test_duration_backends() {
+    let schema = r#"
+components:
+  schemas:
+    Lasts:
+      type: string
+      format: duration
+"#;
+    let generate = |filter: &str| {
+        let openapi = OpenApi::from_str(schema).unwrap();
+        let config = FilterConfig::from_str(filter).unwrap();
+        generate_openapi_types(openapi, config).unwrap()
+    };
+
+    // only `jiff` can keep the months and years of the durations
+    assert!(generate("date_time_backend: time").contains("pub type Lasts = String;"));
+    assert!(generate("date_time_backend: chrono").contains("pub type Lasts = String;"));
+    assert!(generate("date_time_backend: jiff").contains("pub type Lasts = jiff::Span;"));
+    // the type mappings take precedence over the backend
+    let filter = r#"
+date_time_backend: chrono
+type_mappings:
+  - type: string
+    format: duration
+    rust_type: iso8601::Duration
+"#;
+    assert!(generate(filter).contains("pub type Lasts = iso8601::Duration;"));
+}
+
+const SHAPES_SCHEMA: &str = r##"
+components:
+  schemas:
+    Stamp:
+      type: string
+      format: date-time
+    Stamps:
+      type: object
+      additionalProperties:
+        type: string
+        format: date-time
+    StampOrNumber:
+      oneOf:
+        - type: string
+          format: date-time
+        - type: number
+    Calendar:
+      type: object
+      required: [by_day]
+      properties:
+        by_day:
+          type: object
+          additionalProperties:
+            type: string
+            format: date-time
+        lists:
+          type: object
+          additionalProperties:
+            type: array
+            items:
+              type: string
+              format: date-time
+      additionalProperties:
+        type: string
+        format: date-time
+"##;
+
+#[test]
+fn test_time_shapes() {
+    let openapi = OpenApi::from_str(SHAPES_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, date_time_shapes::CODE);
+    // the alias cannot carry `with`, so it becomes a wrapper
+    assert!(s.contains(
+        r#"pub struct Stamp(#[serde(with = "time::serde::rfc3339")] pub time::OffsetDateTime);"#
+    ));
+    assert!(
+        s.contains(r#"    String(#[serde(with = "time::serde::rfc3339")] time::OffsetDateTime),"#)
+    );
+    round_trip::<date_time_shapes::Stamp>(r#""2017-07-21T17:32:28Z""#);
+    round_trip::<date_time_shapes::Stamps>(r#"{"a":"2017-07-21T17:32:28Z"}"#);
+    round_trip::<date_time_shapes::StampOrNumber>(r#""2017-07-21T17:32:28Z""#);
+    round_trip::<date_time_shapes::StampOrNumber>("1.5");
+    round_trip::<date_time_shapes::Calendar>(
+        r#"{"by_day":{"a":"2017-07-21T17:32:28Z"},"lists":{"b":["2017-07-21T17:32:28Z"]},"c":"2017-07-21T17:32:28Z"}"#,
+    );
+    round_trip::<date_time_shapes::Calendar>(r#"{"by_day":{}}"#);
+}
+
+const DATES_SCHEMA: &str = r##"
+components:
+  schemas:
+    Day:
+      type: string
+      format: date
+    Holidays:
+      type: object
+      required: [first]
+      properties:
+        first:
+          type: string
+          format: date
+        last:
+          type: string
+          format: date
+        list:
+          type: array
+          items:
+            type: string
+            format: date
+      additionalProperties:
+        type: string
+        format: date
+"##;
+
+#[test]
+fn test_time_dates() {
+    let openapi = OpenApi::from_str(DATES_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, date_shapes::CODE);
+    assert!(s.contains("mod _time_serde_date {"));
+    round_trip::<date_shapes::Day>(r#""2017-07-21""#);
+    round_trip::<date_shapes::Holidays>(
+        r#"{"first":"2017-07-21","last":"2017-12-31","list":["2017-08-01"],"a":"2017-09-01"}"#,
+    );
+    round_trip::<date_shapes::Holidays>(r#"{"first":"2017-07-21"}"#);
+    assert!(serde_json::from_str::<date_shapes::Day>(r#""2017-07-21T17:32:28Z""#).is_err());
+}
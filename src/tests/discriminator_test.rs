@@ -0,0 +1,326 @@
+use super::fixtures::{assert_generated, discriminator_mapping, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Cat:
+      type: object
+      required: [petType]
+      properties:
+        petType:
+          type: string
+        lives:
+          type: integer
+    Dog:
+      type: object
+      required: [petType]
+      properties:
+        petType:
+          type: string
+        bark:
+          type: string
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+      discriminator:
+        propertyName: petType
+        mapping:
+          cat: '#/components/schemas/Cat'
+"##;
+
+#[test]
+fn test_discriminator() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"#[serde(tag = "petType")]
+pub enum Pet {
+    #[serde(rename = "cat")]
+    Cat(CatWithoutPetType),
+    Dog(DogWithoutPetType),
+}"#
+    ));
+    // the tag is written by the enum, so it uses the copies without it
+    assert!(s.contains("pub struct CatWithoutPetType {\n    pub lives: Option<i32>,\n}"));
+    assert!(s.contains("pub struct DogWithoutPetType {\n    pub bark: Option<String>,\n}"));
+    // the structures themselves are kept as they are
+    assert!(s.contains(
+        "pub struct Cat {\n    pub lives: Option<i32>,\n    #[serde(rename = \"petType\")]\n    pub pet_type: String,\n}"
+    ));
+}
+
+#[test]
+fn test_discriminator_in_property() {
+    const OWNER: &str = r##"
+    Owner:
+      type: object
+      required: [pet]
+      properties:
+        pet:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - $ref: '#/components/schemas/Dog'
+          discriminator:
+            propertyName: petType
+"##;
+
+    let openapi = OpenApi::from_str(&format!("{SCHEMA}{OWNER}")).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include:
+  Owner: "*"
+  Cat: "*"
+  Dog: "*"
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub pet: _UnionCatOrDogByPetType,"));
+    assert!(s.contains(
+        r#"#[serde(tag = "petType")]
+pub enum _UnionCatOrDogByPetType {
+    Cat(CatWithoutPetType),
+    Dog(DogWithoutPetType),
+}"#
+    ));
+}
+
+#[test]
+fn test_discriminator_mappings() {
+    const PROPERTIES: &str = r##"
+    Owner:
+      type: object
+      required: [pet]
+      properties:
+        pet:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - $ref: '#/components/schemas/Dog'
+          discriminator:
+            propertyName: petType
+    Shelter:
+      type: object
+      required: [resident]
+      properties:
+        resident:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - $ref: '#/components/schemas/Dog'
+          discriminator:
+            propertyName: petType
+            mapping:
+              CAT: '#/components/schemas/Cat'
+              DOG: '#/components/schemas/Dog'
+"##;
+
+    let openapi = OpenApi::from_str(&format!("{SCHEMA}{PROPERTIES}")).unwrap();
+    let config =
+        FilterConfig::from_str("include: {Owner: '*', Shelter: '*', Cat: '*', Dog: '*'}").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the same types with other values of the tag are another enum
+    assert!(s.contains("pub pet: _UnionCatOrDogByPetType,"));
+    assert!(s.contains("pub resident: _UnionCatOrDogByPetType2,"));
+    assert!(s.contains(
+        r#"#[serde(tag = "petType")]
+pub enum _UnionCatOrDogByPetType2 {
+    #[serde(rename = "CAT")]
+    Cat(CatWithoutPetType),
+    #[serde(rename = "DOG")]
+    Dog(DogWithoutPetType),
+}"#
+    ));
+    assert_eq!(s.matches("pub enum _UnionCatOrDogByPetType {").count(), 1);
+    assert_eq!(s.matches("pub struct CatWithoutPetType {").count(), 1);
+}
+
+#[test]
+fn test_discriminator_variant_used_directly() {
+    const OWNER: &str = r##"
+    Bird:
+      type: object
+      properties:
+        wings:
+          type: integer
+    Owner:
+      type: object
+      required: [cat, pet]
+      properties:
+        cat:
+          $ref: '#/components/schemas/Cat'
+        pet:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - $ref: '#/components/schemas/Dog'
+          discriminator:
+            propertyName: petType
+        bird:
+          $ref: '#/components/schemas/Bird'
+        flyingPet:
+          oneOf:
+            - $ref: '#/components/schemas/Bird'
+            - $ref: '#/components/schemas/Dog'
+          discriminator:
+            propertyName: petType
+"##;
+
+    let openapi = OpenApi::from_str(&format!("{SCHEMA}{OWNER}")).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the structure used directly keeps the tag, the enums use its copy
+    assert!(s.contains(
+        "pub struct Cat {\n    pub lives: Option<i32>,\n    #[serde(rename = \"petType\")]\n    pub pet_type: String,\n}"
+    ));
+    assert!(s.contains("pub struct CatWithoutPetType {\n    pub lives: Option<i32>,\n}"));
+    assert!(s.contains(
+        r#"#[serde(tag = "petType")]
+pub enum Pet {
+    #[serde(rename = "cat")]
+    Cat(CatWithoutPetType),
+    Dog(DogWithoutPetType),
+}"#
+    ));
+    assert!(s.contains("pub cat: Cat,"));
+    assert!(s.contains("pub pet: _UnionCatOrDogByPetType,"));
+    // there is nothing to remove from the structure without the tag
+    assert!(!s.contains("BirdWithoutPetType"));
+    assert!(s.contains(
+        r#"pub enum _UnionBirdOrDogByPetType {
+    Bird(Bird),
+    Dog(DogWithoutPetType),
+}"#
+    ));
+    assert!(s.contains("pub flying_pet: Option<_UnionBirdOrDogByPetType>,"));
+}
+
+#[test]
+fn test_discriminator_independent_of_filter() {
+    const OWNER: &str = r##"
+    Owner:
+      type: object
+      properties:
+        cat:
+          $ref: '#/components/schemas/Cat'
+"##;
+
+    // the structure is the same whether or not the types using it directly
+    // are selected
+    let cat = "pub struct Cat {\n    pub lives: Option<i32>,\n    #[serde(rename = \"petType\")]\n    pub pet_type: String,\n}";
+    for filter in ["include: {Pet: '*', Cat: '*', Dog: '*'}", "{}"] {
+        let openapi = OpenApi::from_str(&format!("{SCHEMA}{OWNER}")).unwrap();
+        let config = FilterConfig::from_str(filter).unwrap();
+        let s = generate_openapi_types(openapi, config).unwrap();
+        assert!(s.contains(cat), "{filter}");
+        assert!(s.contains("    Cat(CatWithoutPetType),\n"), "{filter}");
+    }
+}
+
+const MAPPING_SCHEMA: &str = r##"
+components:
+  schemas:
+    house_cat:
+      type: object
+      required: [petType]
+      properties:
+        petType:
+          type: string
+        lives:
+          type: integer
+    Dog:
+      type: object
+      required: [petType]
+      properties:
+        petType:
+          type: string
+    DogWithoutPetType:
+      type: object
+      properties:
+        bark:
+          type: string
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/house_cat'
+        - $ref: '#/components/schemas/Dog'
+      discriminator:
+        propertyName: petType
+        mapping:
+          CAT: '#/components/schemas/house_cat'
+          DOG: Dog
+"##;
+
+#[test]
+fn test_discriminator_mapping_of_copies() {
+    let openapi = OpenApi::from_str(MAPPING_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, discriminator_mapping::CODE);
+    // the values of the mapping refer to the original schemas, but they are
+    // the values of the copies, even if the name of the copy is taken
+    assert!(s.contains(
+        r#"pub enum Pet {
+    #[serde(rename = "CAT")]
+    HouseCat(HouseCatWithoutPetType),
+    #[serde(rename = "DOG")]
+    Dog(DogWithoutPetType2),
+}"#
+    ));
+
+    let pet = round_trip::<discriminator_mapping::Pet>(r#"{"petType":"CAT","lives":9}"#);
+    assert!(matches!(pet, discriminator_mapping::Pet::HouseCat(cat) if cat.lives == Some(9)));
+    let pet = round_trip::<discriminator_mapping::Pet>(r#"{"petType":"DOG"}"#);
+    assert!(matches!(pet, discriminator_mapping::Pet::Dog(_)));
+    assert!(serde_json::from_str::<discriminator_mapping::Pet>(r#"{"petType":"Dog"}"#).is_err());
+}
+
+#[test]
+fn test_discriminator_with_unsupported_members() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        petType:
+          type: string
+    Owner:
+      type: object
+      properties:
+        pet:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - type: array
+              items:
+                $ref: '#/components/schemas/Cat'
+          discriminator:
+            propertyName: petType
+        name:
+          oneOf:
+            - $ref: '#/components/schemas/Cat'
+            - type: string
+          discriminator:
+            propertyName: petType
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+
+    // an array or a primitive has no property for the tag
+    let errors: Vec<_> = err.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        [
+            "#/components/schemas/Owner/properties/name/oneOf/1: \
+                type \"string\" cannot be used with discriminator",
+            "#/components/schemas/Owner/properties/pet/oneOf/1: \
+                type \"Cat\" cannot be used with discriminator",
+        ]
+    );
+}
@@ -0,0 +1,126 @@
+use super::fixtures::{assert_generated, double_option_with, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    UserPatch:
+      type: object
+      required: [id, email]
+      properties:
+        id:
+          type: integer
+        email:
+          type: string
+          nullable: true
+        name:
+          type: string
+        nickname:
+          type: string
+          nullable: true
+        manager:
+          allOf:
+            - $ref: '#/components/schemas/User'
+            - nullable: true
+    User:
+      type: object
+      properties:
+        id:
+          type: integer
+"##;
+
+#[test]
+fn test_double_option() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("double_option: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct UserPatch {
+    pub email: Option<String>,
+    pub id: i32,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub manager: Option<Option<User>>,
+    pub name: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub nickname: Option<Option<String>>,
+}"#
+    ));
+}
+
+#[test]
+fn test_double_option_serialize() {
+    let filter = r#"
+double_option: true
+serialize: true
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub nickname: Option<Option<String>>,
+"#
+    ));
+}
+
+#[test]
+fn test_single_option_by_default() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub nickname: Option<String>,"));
+    assert!(s.contains("pub manager: Option<User>,"));
+    assert!(!s.contains("double_option"));
+}
+
+const WITH_SCHEMA: &str = r##"
+components:
+  schemas:
+    EventPatch:
+      type: object
+      properties:
+        at:
+          type: string
+          format: date-time
+          nullable: true
+        reminders:
+          type: array
+          nullable: true
+          items:
+            type: string
+            format: date-time
+"##;
+
+#[test]
+fn test_double_option_with_module() {
+    let filter = r#"
+double_option: true
+serialize: true
+"#;
+    let openapi = OpenApi::from_str(WITH_SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, double_option_with::CODE);
+    // the module of the type mapping is composed with the double option
+    assert!(s.contains(
+        r#"    #[serde(with = "_time_serde_rfc3339_double_option", default)]
+    pub at: Option<Option<time::OffsetDateTime>>,
+"#
+    ));
+    assert!(!s.contains("::serde_with::rust::double_option"));
+    let value = round_trip::<double_option_with::EventPatch>("{}");
+    assert!(value.at.is_none());
+    let value = round_trip::<double_option_with::EventPatch>(r#"{"at":null,"reminders":null}"#);
+    assert_eq!(value.at, Some(None));
+    assert_eq!(value.reminders, Some(None));
+    let value = round_trip::<double_option_with::EventPatch>(
+        r#"{"at":"2017-07-21T17:32:28Z","reminders":["2017-07-21T17:32:28Z"]}"#,
+    );
+    assert!(matches!(value.at, Some(Some(_))));
+    assert!(matches!(value.reminders, Some(Some(_))));
+}
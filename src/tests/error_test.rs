@@ -0,0 +1,173 @@
+use super::temp_dir::TempDir;
+use crate::*;
+use std::fs;
+
+#[test]
+fn test_loading_errors() {
+    let temp_dir = TempDir::new("loading_errors");
+    let dir = temp_dir.path();
+    fs::write(dir.join("schema.txt"), "openapi: 3.0.0").unwrap();
+    fs::write(dir.join("schema.yaml"), "components: [").unwrap();
+
+    let err = OpenApi::from_file(dir.join("missing.yaml")).err().unwrap();
+    assert!(matches!(err, Error::Io { path, .. } if path.ends_with("missing.yaml")));
+
+    let err = OpenApi::from_file(dir.join("schema.txt")).err().unwrap();
+    assert!(matches!(err, Error::UnknownFileFormat { path } if path.ends_with("schema.txt")));
+
+    let err = OpenApi::from_file(dir.join("schema.yaml")).err().unwrap();
+    assert!(matches!(err, Error::Parse { path: Some(path), .. } if path.ends_with("schema.yaml")));
+
+    let err = FilterConfig::from_str("include: 42").err().unwrap();
+    assert!(matches!(err, Error::Parse { path: None, .. }));
+}
+
+#[test]
+fn test_generation_errors() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        status:
+          $ref: 'common.yaml#/components/schemas/Status'
+        item:
+          $ref: '#/components/schemas/Item'
+        price:
+          allOf:
+            - type: string
+            - type: object
+        "type!":
+          type: string
+"##;
+
+    let generate = |filter: &str| {
+        let openapi = OpenApi::from_str(SCHEMA).unwrap();
+        let config = FilterConfig::from_str(filter).unwrap();
+        generate_openapi_types(openapi, config).unwrap_err()
+    };
+
+    let err = generate("include: {Order: [status]}");
+    assert!(matches!(
+        &err,
+        Error::UnresolvedReference { location, reference }
+            if location == "#/components/schemas/Order/properties/status"
+                && reference == "common.yaml#/components/schemas/Status"
+    ));
+    assert_eq!(
+        err.to_string(),
+        "#/components/schemas/Order/properties/status: \
+            unresolved reference \"common.yaml#/components/schemas/Status\""
+    );
+
+    // all the problems are reported at once
+    let err = generate("include: {Order: '*'}");
+    let locations: Vec<_> = err
+        .iter()
+        .map(|e| match e {
+            Error::Unsupported { location, .. }
+            | Error::UnresolvedReference { location, .. }
+            | Error::UntranslatableName { location }
+            | Error::MissingSchema { location, .. } => location.as_str(),
+            _ => panic!("unexpected error: {e}"),
+        })
+        .collect();
+    assert_eq!(
+        locations,
+        [
+            "#/components/schemas/Order/properties/price/allOf/0",
+            "#/components/schemas/Order/properties/status",
+            "#/components/schemas/Order/properties/type!",
+            "#/components/schemas/Order/properties/item",
+        ]
+    );
+    assert!(matches!(&err, Error::Multiple(errors) if errors.len() == 4));
+    assert!(matches!(
+        err.iter().last(),
+        Some(Error::MissingSchema { name, .. }) if name == "Item"
+    ));
+}
+
+#[test]
+fn test_error_locations() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - type: string
+        - type: object
+          properties:
+            "a/b":
+              type: string
+            tags:
+              type: array
+              items:
+                $ref: '#/components/schemas/Tag'
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let errors: Vec<_> = err.iter().collect();
+    assert!(matches!(
+        errors[0],
+        Error::UntranslatableName { location }
+            if location == "#/components/schemas/Pet/oneOf/1/properties/a~1b"
+    ));
+    assert!(matches!(
+        errors[1],
+        Error::MissingSchema { location, name }
+            if location == "#/components/schemas/Pet/oneOf/1/properties/tags/items" && name == "Tag"
+    ));
+    assert_eq!(errors.len(), 2);
+}
+
+#[test]
+fn test_unknown_types() {
+    const SCHEMA: &str = r##"
+swagger: "2.0"
+info: { title: Upload, version: 1.0.0 }
+paths: {}
+definitions:
+  Upload:
+    type: object
+    properties:
+      data:
+        type: file
+      created:
+        type: date
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let errors: Vec<_> = err
+        .iter()
+        .map(|e| match e {
+            Error::MissingSchema { location, name } => (location.as_str(), name.as_str()),
+            _ => panic!("unexpected error: {e}"),
+        })
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            ("#/components/schemas/Upload/properties/created", "date"),
+            ("#/components/schemas/Upload/properties/data", "file"),
+        ]
+    );
+
+    // the mapped types are not missing
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+type_mappings:
+  - { type: file, rust_type: Vec<u8> }
+  - { type: date, rust_type: String }
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub data: Option<Vec<u8>>,"));
+}
@@ -0,0 +1,305 @@
+use super::temp_dir::TempDir;
+use crate::*;
+use std::fs;
+
+/// Writes the files into a new temporary directory
+fn write_files(test_name: &str, files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new(test_name);
+    fs::create_dir_all(dir.path().join("common")).unwrap();
+    for (name, data) in files {
+        fs::write(dir.path().join(name), data).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn test_external_refs() {
+    const ROOT: &str = r##"
+components:
+  schemas:
+    Money:
+      type: string
+    Order:
+      type: object
+      required: [price, error]
+      properties:
+        price:
+          $ref: './common/money.yaml#/components/schemas/Money'
+        error:
+          $ref: 'common/errors.json#/Error'
+"##;
+    const MONEY: &str = r##"
+components:
+  schemas:
+    Money:
+      type: object
+      required: [amount]
+      properties:
+        amount:
+          type: number
+        currency:
+          $ref: '#/components/schemas/Currency'
+    Currency:
+      type: string
+      enum: [USD, EUR]
+"##;
+    const ERRORS: &str = r##"
+{
+  "Error": {
+    "type": "object",
+    "properties": {
+      "cause": { "$ref": "#/Error" },
+      "order": { "$ref": "../root.yaml#/components/schemas/Order" }
+    }
+  }
+}
+"##;
+
+    let dir = write_files(
+        "external_refs",
+        &[
+            ("root.yaml", ROOT),
+            ("common/money.yaml", MONEY),
+            ("common/errors.json", ERRORS),
+        ],
+    );
+    let openapi = OpenApi::from_file(dir.path().join("root.yaml")).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the name "Money" is already taken in the root file
+    assert!(s.contains("pub price: MoneyMoney,"));
+    assert!(s.contains("pub struct MoneyMoney {"));
+    assert!(s.contains("pub currency: Option<Currency>,"));
+    assert!(s.contains("pub enum Currency {"));
+    // cyclic references
+    assert!(s.contains("pub error: std::boxed::Box<Error>,"));
+    assert!(s.contains("pub cause: Option<std::boxed::Box<Error>>,"));
+    assert!(s.contains("pub order: Option<Order>,"));
+}
+
+#[test]
+fn test_root_external_ref() {
+    const ROOT: &str = r##"
+components:
+  schemas:
+    Money:
+      $ref: './common/money.yaml#/Money'
+    Order:
+      type: object
+      required: [price]
+      properties:
+        price:
+          $ref: '#/components/schemas/Money'
+"##;
+    const MONEY: &str = r##"
+Money:
+  type: object
+  required: [amount]
+  properties:
+    amount:
+      type: number
+"##;
+
+    let dir = write_files(
+        "root_external_ref",
+        &[("root.yaml", ROOT), ("common/money.yaml", MONEY)],
+    );
+    let openapi = OpenApi::from_file(dir.path().join("root.yaml")).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the schema that is only a reference becomes a type alias
+    assert!(s.contains("pub type Money = MoneyMoney;"));
+    assert!(s.contains("pub struct MoneyMoney {\n    pub amount: f64,\n}"));
+    assert!(s.contains("pub price: Money,"));
+}
+
+#[test]
+fn test_external_ref_name_in_other_case() {
+    const ROOT: &str = r##"
+components:
+  schemas:
+    Money:
+      type: string
+    CommonMoney:
+      type: integer
+    Order:
+      type: object
+      required: [price]
+      properties:
+        price:
+          $ref: './common.yaml#/Money'
+"##;
+    const COMMON: &str = r##"
+Money:
+  type: object
+  required: [amount]
+  properties:
+    amount:
+      type: number
+"##;
+
+    let dir = write_files(
+        "external_ref_name_in_other_case",
+        &[("root.yaml", ROOT), ("common.yaml", COMMON)],
+    );
+    let openapi = OpenApi::from_file(dir.path().join("root.yaml")).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // `common_Money` would be the same type as `CommonMoney`
+    assert!(s.contains("pub type CommonMoney = i32;"));
+    assert!(s.contains("pub price: CommonMoney3,"));
+    assert!(s.contains("pub struct CommonMoney3 {\n    pub amount: f64,\n}"));
+}
+
+#[test]
+fn test_unresolvable_external_ref() {
+    const ROOT: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        price:
+          $ref: './missing.yaml#/components/schemas/Money'
+"##;
+
+    let dir = write_files("unresolvable_ref", &[("root.yaml", ROOT)]);
+    let err = OpenApi::from_file(dir.path().join("root.yaml"))
+        .err()
+        .unwrap();
+    assert!(matches!(
+        &err,
+        Error::UnresolvedReference { location, reference }
+            if location == "#/components/schemas/Order/properties/price"
+                && reference == "./missing.yaml#/components/schemas/Money"
+    ));
+
+    // the location of the reference in an operation
+    const OPERATION: &str = r##"
+paths:
+  /pets:
+    get:
+      responses:
+        200:
+          content:
+            application/json:
+              schema:
+                $ref: './missing.yaml#/Pet'
+"##;
+    let dir = write_files("unresolvable_operation_ref", &[("root.yaml", OPERATION)]);
+    let err = OpenApi::from_file(dir.path().join("root.yaml"))
+        .err()
+        .unwrap();
+    assert!(matches!(
+        &err,
+        Error::UnresolvedReference { location, .. }
+            if location == "#/paths/~1pets/get/responses/200/content/application~1json/schema"
+    ));
+
+    // without resolving, it's an error during generation, not a panic
+    let openapi = OpenApi::from_str(ROOT).unwrap();
+    let config = FilterConfig::default();
+    assert!(generate_openapi_types(openapi, config).is_err());
+}
+
+#[test]
+fn test_external_refs_in_operations() {
+    const ROOT: &str = r##"
+paths:
+  /pets/{id}:
+    parameters:
+      - $ref: '#/components/parameters/PetId'
+    get:
+      operationId: getPet
+      responses:
+        200:
+          description: The pet
+          content:
+            application/json:
+              schema:
+                $ref: './common/pets.yaml#/Pet'
+        default:
+          $ref: '#/components/responses/Error'
+  /orders:
+    post:
+      operationId: createOrder
+      requestBody:
+        $ref: '#/components/requestBodies/NewOrder'
+      responses:
+        201:
+          description: Created
+components:
+  parameters:
+    PetId:
+      name: id
+      in: path
+      schema:
+        $ref: './common/pets.yaml#/PetId'
+  requestBodies:
+    NewOrder:
+      content:
+        application/json:
+          schema:
+            $ref: './common/orders.yaml#/NewOrder'
+  responses:
+    Error:
+      description: Error
+      content:
+        application/json:
+          schema:
+            $ref: './common/errors.yaml#/Error'
+"##;
+    const PETS: &str = r##"
+PetId:
+  type: integer
+  format: int64
+Pet:
+  type: object
+  required: [id]
+  properties:
+    id:
+      $ref: '#/PetId'
+"##;
+    const ORDERS: &str = r##"
+NewOrder:
+  type: object
+  properties:
+    note:
+      type: string
+"##;
+    const ERRORS: &str = r##"
+Error:
+  type: object
+  properties:
+    message:
+      type: string
+"##;
+
+    let dir = write_files(
+        "external_refs_in_operations",
+        &[
+            ("root.yaml", ROOT),
+            ("common/pets.yaml", PETS),
+            ("common/orders.yaml", ORDERS),
+            ("common/errors.yaml", ERRORS),
+        ],
+    );
+    let openapi = OpenApi::from_file(dir.path().join("root.yaml")).unwrap();
+    let config = FilterConfig::from_str("include_operations: [getPet]").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the schemas of the shared parameters and responses and of the
+    // operations themselves
+    assert!(s.contains("pub type PetId = i64;"));
+    assert!(s.contains("pub struct Pet {\n    pub id: PetId,\n}"));
+    assert!(s.contains("pub struct Error {"));
+    assert!(!s.contains("NewOrder"));
+
+    let openapi = OpenApi::from_file(dir.path().join("root.yaml")).unwrap();
+    let config = FilterConfig::from_str("include_operations: [createOrder]").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct NewOrder {\n    pub note: Option<String>,\n}"));
+}
@@ -0,0 +1,205 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    User:
+      type: object
+      properties:
+        name:
+          type: string
+        address:
+          type: object
+          properties:
+            city:
+              type: string
+    Order:
+      allOf:
+        - type: object
+          properties:
+            id:
+              type: string
+        - type: object
+          properties:
+            total:
+              type: number
+"##;
+
+const CONFIG: &str = r#"
+include:
+  Usr: "*"
+  User: [name, emial]
+  Order: all
+exclude:
+  Billing*: "*"
+"#;
+
+#[test]
+fn test_valid_filter() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include:
+  User*: ["na*", address]
+  UserAddress: [city]
+  Order: [id, total]
+strict: true
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct UserAddress {\n    pub city: Option<String>,\n}"));
+    assert!(s.contains(
+        "pub struct Order {\n    pub id: Option<String>,\n    pub total: Option<f64>,\n}"
+    ));
+}
+
+#[test]
+fn test_invalid_filter() {
+    // without the strict mode, the problems are only warnings
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(CONFIG).unwrap();
+    let (s, warnings) = generate_openapi_types_with_warnings(openapi, config).unwrap();
+    assert!(s.contains("pub struct User {\n    pub name: Option<String>,\n}"));
+    assert_eq!(
+        warnings,
+        [
+            "#/include/Order: expected \"*\" or a list of properties, found \"all\"",
+            "#/include/User: unknown property \"emial\"",
+            "#/include/Usr: unknown schema \"Usr\"",
+            "#/exclude/Billing*: unknown schema \"Billing*\"",
+        ]
+    );
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(&format!("{CONFIG}strict: true")).unwrap();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let errors: Vec<_> = err.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        [
+            "#/include/Order: expected \"*\" or a list of properties, found \"all\"",
+            "#/include/User: unknown property \"emial\"",
+            "#/include/Usr: unknown schema \"Usr\"",
+            "#/exclude/Billing*: unknown schema \"Billing*\"",
+        ]
+    );
+    assert!(err.iter().all(|e| matches!(e, Error::InvalidFilter { .. })));
+}
+
+#[test]
+fn test_filter_with_broken_schema() {
+    let schema = r#"
+components:
+  schemas:
+    Broken:
+      allOf:
+        - type: object
+          properties:
+            id:
+              type: string
+        - type: object
+          properties:
+            id:
+              type: integer
+"#;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::from_str("include: {Broken: [id]}\nstrict: true").unwrap();
+    // the schema that cannot be processed is still known to the filter
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let errors: Vec<_> = err.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        [
+            "#/components/schemas/Broken/allOf/1/properties/id: conflicting definitions of property \"id\" in `allOf`"
+        ]
+    );
+}
+
+#[test]
+fn test_additional_properties_in_filter() {
+    let schema = r#"
+components:
+  schemas:
+    Labels:
+      type: object
+      properties:
+        name:
+          type: string
+        nested:
+          type: object
+          properties:
+            id:
+              type: string
+          additionalProperties: true
+      additionalProperties:
+        type: string
+    Closed:
+      type: object
+      properties:
+        name:
+          type: string
+      additionalProperties: false
+"#;
+    let config = r#"
+include:
+  Labels: [name, nested, additionalProperties]
+  LabelsNested: [additionalProperties]
+strict: true
+"#;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::from_str(config).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains(
+        "    #[serde(flatten)]\n    pub additional_properties: std::collections::HashMap<String, String>,\n"
+    ));
+    assert!(s.contains(
+        "pub struct LabelsNested {\n    #[serde(flatten)]\n    pub additional_properties: std::collections::HashMap<String, serde_json::Value>,\n}"
+    ));
+
+    // the object without additional properties has no such field
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config =
+        FilterConfig::from_str("include: {Closed: [additionalProperties]}\nstrict: true").unwrap();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "#/include/Closed: unknown property \"additionalProperties\""
+    );
+}
+
+#[test]
+fn test_warnings_of_failed_generation() {
+    const BROKEN_SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      properties:
+        item:
+          $ref: '#/components/schemas/Item'
+"##;
+
+    let inputs = || {
+        let openapi = OpenApi::from_str(BROKEN_SCHEMA).unwrap();
+        let config = FilterConfig::from_str("include: {Order: '*', Usr: '*'}").unwrap();
+        (openapi, config)
+    };
+
+    // the warnings are returned along with the error, not printed
+    let (openapi, config) = inputs();
+    let err = generate_openapi_types_with_warnings(openapi, config).unwrap_err();
+    assert!(matches!(&err, Error::WithWarnings { error, .. }
+        if matches!(**error, Error::MissingSchema { .. })));
+    assert_eq!(err.warnings(), ["#/include/Usr: unknown schema \"Usr\""]);
+    assert_eq!(
+        err.to_string(),
+        "#/components/schemas/Order/properties/item: reference to missing schema \"Item\""
+    );
+    assert_eq!(err.iter().count(), 1);
+
+    // without the warnings, the error is returned as is
+    let (openapi, config) = inputs();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    assert!(matches!(err, Error::MissingSchema { .. }));
+}
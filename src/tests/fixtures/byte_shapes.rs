@@ -0,0 +1,88 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// (De)serialization of `Option<Vec<Vec<u8>>>` by `serde_with::As::<serde_with::base64::Base64>`
+mod _serde_with_as_serde_with_base64_base64_option_vec {
+    struct Ser<'a>(&'a Vec<u8>);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serde_with::As::<serde_with::base64::Base64>::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(Vec<u8>);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            serde_with::As::<serde_with::base64::Base64>::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<Vec<Vec<u8>>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(|v| v.iter().map(Ser).collect::<Vec<_>>()), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<Vec<u8>>>, D::Error> {
+        let value: Option<Vec<De>> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|v| v.into_iter().map(|v| v.0).collect()))
+    }
+}
+
+/// (De)serialization of `std::collections::HashMap<String, Vec<u8>>` by `serde_with::As::<serde_with::base64::Base64>`
+mod _serde_with_as_serde_with_base64_base64_map {
+    struct Ser<'a>(&'a Vec<u8>);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serde_with::As::<serde_with::base64::Base64>::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(Vec<u8>);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            serde_with::As::<serde_with::base64::Base64>::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &std::collections::HashMap<String, Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.iter().map(|(k, v)| (k, Ser(v))).collect::<std::collections::HashMap<_, _>>(), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<std::collections::HashMap<String, Vec<u8>>, D::Error> {
+        let value: std::collections::HashMap<String, De> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.into_iter().map(|(k, v)| (k, v.0)).collect())
+    }
+}
+
+/// Attachment
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_serde_with_as_serde_with_base64_base64_option_vec", default)]
+    pub chunks: Option<Vec<Vec<u8>>>,
+    #[serde(with = "_serde_with_as_serde_with_base64_base64_map")]
+    pub parts: std::collections::HashMap<String, Vec<u8>>,
+}
+
+/// Blob
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Blob(#[serde(with = "serde_with::As::<serde_with::base64::Base64>")] pub Vec<u8>);
+
+/// BlobOrNumber
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BlobOrNumber {
+    String(#[serde(with = "serde_with::As::<serde_with::base64::Base64>")] Vec<u8>),
+    Number(f64),
+}
+
+/// Blobs
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Blobs(#[serde(with = "_serde_with_as_serde_with_base64_base64_map")] pub std::collections::HashMap<String, Vec<u8>>);
@@ -0,0 +1,125 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// (De)serialization of `time::Date` in RFC 3339, "2017-07-21"
+mod _time_serde_date {
+    use time::macros::format_description;
+
+    pub fn serialize<S: serde::Serializer>(value: &time::Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = value.format(format_description!("[year]-[month]-[day]"));
+        serializer.serialize_str(&value.map_err(serde::ser::Error::custom)?)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<time::Date, D::Error> {
+        let value: String = serde::Deserialize::deserialize(deserializer)?;
+        time::Date::parse(&value, format_description!("[year]-[month]-[day]")).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Day
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Day(#[serde(with = "_time_serde_date")] pub time::Date);
+
+/// (De)serialization of `Option<time::Date>` by `super::_time_serde_date`
+mod _time_serde_date_option {
+    struct Ser<'a>(&'a time::Date);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::_time_serde_date::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::Date);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::_time_serde_date::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<time::Date>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(Ser), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<time::Date>, D::Error> {
+        let value: Option<De> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|v| v.0))
+    }
+}
+
+/// (De)serialization of `Option<Vec<time::Date>>` by `super::_time_serde_date`
+mod _time_serde_date_option_vec {
+    struct Ser<'a>(&'a time::Date);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::_time_serde_date::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::Date);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::_time_serde_date::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<Vec<time::Date>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(|v| v.iter().map(Ser).collect::<Vec<_>>()), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Vec<time::Date>>, D::Error> {
+        let value: Option<Vec<De>> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|v| v.into_iter().map(|v| v.0).collect()))
+    }
+}
+
+/// (De)serialization of `std::collections::HashMap<String, time::Date>` by `super::_time_serde_date`
+mod _time_serde_date_map {
+    struct Ser<'a>(&'a time::Date);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::_time_serde_date::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::Date);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::_time_serde_date::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &std::collections::HashMap<String, time::Date>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.iter().map(|(k, v)| (k, Ser(v))).collect::<std::collections::HashMap<_, _>>(), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<std::collections::HashMap<String, time::Date>, D::Error> {
+        let value: std::collections::HashMap<String, De> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.into_iter().map(|(k, v)| (k, v.0)).collect())
+    }
+}
+
+/// Holidays
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Holidays {
+    #[serde(with = "_time_serde_date")]
+    pub first: time::Date,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_time_serde_date_option", default)]
+    pub last: Option<time::Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_time_serde_date_option_vec", default)]
+    pub list: Option<Vec<time::Date>>,
+    #[serde(flatten)]
+    #[serde(with = "_time_serde_date_map")]
+    pub additional_properties: std::collections::HashMap<String, time::Date>,
+}
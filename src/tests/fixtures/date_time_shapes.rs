@@ -0,0 +1,91 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// (De)serialization of `std::collections::HashMap<String, time::OffsetDateTime>` by `time::serde::rfc3339`
+mod _time_serde_rfc3339_map {
+    struct Ser<'a>(&'a time::OffsetDateTime);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            time::serde::rfc3339::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::OffsetDateTime);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            time::serde::rfc3339::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &std::collections::HashMap<String, time::OffsetDateTime>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.iter().map(|(k, v)| (k, Ser(v))).collect::<std::collections::HashMap<_, _>>(), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<std::collections::HashMap<String, time::OffsetDateTime>, D::Error> {
+        let value: std::collections::HashMap<String, De> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.into_iter().map(|(k, v)| (k, v.0)).collect())
+    }
+}
+
+/// (De)serialization of `Option<std::collections::HashMap<String, Vec<time::OffsetDateTime>>>` by `time::serde::rfc3339`
+mod _time_serde_rfc3339_option_map_vec {
+    struct Ser<'a>(&'a time::OffsetDateTime);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            time::serde::rfc3339::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::OffsetDateTime);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            time::serde::rfc3339::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<std::collections::HashMap<String, Vec<time::OffsetDateTime>>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(|v| v.iter().map(|(k, v)| (k, v.iter().map(Ser).collect::<Vec<_>>())).collect::<std::collections::HashMap<_, _>>()), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<std::collections::HashMap<String, Vec<time::OffsetDateTime>>>, D::Error> {
+        let value: Option<std::collections::HashMap<String, Vec<De>>> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(value.map(|v| v.into_iter().map(|(k, v)| (k, v.into_iter().map(|v| v.0).collect())).collect()))
+    }
+}
+
+/// Calendar
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Calendar {
+    #[serde(with = "_time_serde_rfc3339_map")]
+    pub by_day: std::collections::HashMap<String, time::OffsetDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_time_serde_rfc3339_option_map_vec", default)]
+    pub lists: Option<std::collections::HashMap<String, Vec<time::OffsetDateTime>>>,
+    #[serde(flatten)]
+    #[serde(with = "_time_serde_rfc3339_map")]
+    pub additional_properties: std::collections::HashMap<String, time::OffsetDateTime>,
+}
+
+/// Stamp
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stamp(#[serde(with = "time::serde::rfc3339")] pub time::OffsetDateTime);
+
+/// StampOrNumber
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum StampOrNumber {
+    String(#[serde(with = "time::serde::rfc3339")] time::OffsetDateTime),
+    Number(f64),
+}
+
+/// Stamps
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Stamps(#[serde(with = "_time_serde_rfc3339_map")] pub std::collections::HashMap<String, time::OffsetDateTime>);
@@ -0,0 +1,51 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// Dog
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Dog {
+    #[serde(rename = "petType")]
+    pub pet_type: String,
+}
+
+/// DogWithoutPetType
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DogWithoutPetType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bark: Option<String>,
+}
+
+/// DogWithoutPetType2
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DogWithoutPetType2 {
+}
+
+/// Pet
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "petType")]
+pub enum Pet {
+    #[serde(rename = "CAT")]
+    HouseCat(HouseCatWithoutPetType),
+    #[serde(rename = "DOG")]
+    Dog(DogWithoutPetType2),
+}
+
+/// house_cat
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HouseCat {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lives: Option<i32>,
+    #[serde(rename = "petType")]
+    pub pet_type: String,
+}
+
+/// house_catWithoutPetType
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HouseCatWithoutPetType {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lives: Option<i32>,
+}
@@ -0,0 +1,73 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// (De)serialization of `Option<Option<time::OffsetDateTime>>` by `time::serde::rfc3339`
+mod _time_serde_rfc3339_double_option {
+    struct Ser<'a>(&'a time::OffsetDateTime);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            time::serde::rfc3339::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::OffsetDateTime);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            time::serde::rfc3339::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<Option<time::OffsetDateTime>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(|value| value.as_ref().map(Ser)), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Option<time::OffsetDateTime>>, D::Error> {
+        let value: Option<De> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Some(value.map(|v| v.0)))
+    }
+}
+
+/// (De)serialization of `Option<Option<Vec<time::OffsetDateTime>>>` by `time::serde::rfc3339`
+mod _time_serde_rfc3339_double_option_vec {
+    struct Ser<'a>(&'a time::OffsetDateTime);
+
+    impl serde::Serialize for Ser<'_> {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            time::serde::rfc3339::serialize(self.0, serializer)
+        }
+    }
+
+    struct De(time::OffsetDateTime);
+
+    impl<'de> serde::Deserialize<'de> for De {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            time::serde::rfc3339::deserialize(deserializer).map(De)
+        }
+    }
+
+    pub fn serialize<S: serde::Serializer>(value: &Option<Option<Vec<time::OffsetDateTime>>>, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&value.as_ref().map(|value| value.as_ref().map(|v| v.iter().map(Ser).collect::<Vec<_>>())), serializer)
+    }
+
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Option<Vec<time::OffsetDateTime>>>, D::Error> {
+        let value: Option<Vec<De>> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Some(value.map(|v| v.into_iter().map(|v| v.0).collect())))
+    }
+}
+
+/// EventPatch
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EventPatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_time_serde_rfc3339_double_option", default)]
+    pub at: Option<Option<time::OffsetDateTime>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "_time_serde_rfc3339_double_option_vec", default)]
+    pub reminders: Option<Option<Vec<time::OffsetDateTime>>>,
+}
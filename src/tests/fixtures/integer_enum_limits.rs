@@ -0,0 +1,35 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// Limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Limit {
+    ValueMinus9223372036854775808,
+    Value9223372036854775807,
+    Unknown(i64),
+}
+
+impl<'de> serde::Deserialize<'de> for Limit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <i64 as serde::Deserialize>::deserialize(deserializer)? {
+            -9223372036854775808 => Ok(Limit::ValueMinus9223372036854775808),
+            9223372036854775807 => Ok(Limit::Value9223372036854775807),
+            value => Ok(Limit::Unknown(value)),
+        }
+    }
+}
+
+impl serde::Serialize for Limit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Limit::ValueMinus9223372036854775808 => -9223372036854775808,
+            Limit::Value9223372036854775807 => 9223372036854775807,
+            Limit::Unknown(value) => *value,
+        };
+        serializer.serialize_i64(value)
+    }
+}
@@ -0,0 +1,74 @@
+//! The code generated for some of the test specifications, compiled, so that
+//! the tests can check how the generated types read and write the values.
+//! The generated text is checked by the tests of the features themselves,
+//! the fixtures are only for the behavior that the text does not show (serde
+//! modules, tags, null values).
+
+/// The generated code of `SHAPES_SCHEMA` from `date_time_test`
+#[allow(dead_code)]
+pub mod date_time_shapes {
+    include!("date_time_shapes.rs");
+    pub const CODE: &str = include_str!("date_time_shapes.rs");
+}
+
+/// The generated code of `DATES_SCHEMA` from `date_time_test`
+#[allow(dead_code)]
+pub mod date_shapes {
+    include!("date_shapes.rs");
+    pub const CODE: &str = include_str!("date_shapes.rs");
+}
+
+/// The generated code of `MAPPING_SCHEMA` from `discriminator_test`
+#[allow(dead_code)]
+pub mod discriminator_mapping {
+    include!("discriminator_mapping.rs");
+    pub const CODE: &str = include_str!("discriminator_mapping.rs");
+}
+
+/// The generated code of `WITH_SCHEMA` from `double_option_test`
+#[allow(dead_code)]
+pub mod double_option_with {
+    include!("double_option_with.rs");
+    pub const CODE: &str = include_str!("double_option_with.rs");
+}
+
+/// The generated code of `BYTE_SHAPES_SCHEMA` from `formats_test`
+#[allow(dead_code)]
+pub mod byte_shapes {
+    include!("byte_shapes.rs");
+    pub const CODE: &str = include_str!("byte_shapes.rs");
+}
+
+/// The generated code of `NULLABLE_SCHEMA` from `integer_enum_test`
+#[allow(dead_code)]
+pub mod nullable_enums {
+    include!("nullable_enums.rs");
+    pub const CODE: &str = include_str!("nullable_enums.rs");
+}
+
+/// The generated code of `REQUIRED_NULLABLE_SCHEMA` from `serialize_test`
+#[allow(dead_code)]
+pub mod required_nullable {
+    include!("required_nullable.rs");
+    pub const CODE: &str = include_str!("required_nullable.rs");
+}
+
+/// The generated code of `LIMITS_SCHEMA` from `unknown_enum_test`
+#[allow(dead_code, unused_imports)]
+pub mod integer_enum_limits {
+    include!("integer_enum_limits.rs");
+    pub const CODE: &str = include_str!("integer_enum_limits.rs");
+}
+
+/// Checks that the generated code is the one compiled from the fixture, so
+/// that the tests check the types that are generated now
+pub fn assert_generated(generated: &str, code: &str) {
+    assert_eq!(generated.trim_end(), code.trim_end());
+}
+
+/// Reads the value from JSON and checks that it is written back the same way
+pub fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(json: &str) -> T {
+    let value: T = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    value
+}
@@ -0,0 +1,49 @@
+// # OpenApi Types
+// GENERATED AUTOMATICALLY, ALL THE CHANGES
+// YOU MAKE WILL BE REWRITTEN DURING
+// THE NEXT BUILD
+
+use serde::{Deserialize, Serialize};
+
+/// Level
+pub type Level = Option<LevelItem>;
+
+/// LevelItem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(i64)]
+pub enum LevelItem {
+    Value1 = 1,
+    Value2 = 2,
+}
+
+impl<'de> serde::Deserialize<'de> for LevelItem {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <i64 as serde::Deserialize>::deserialize(deserializer)? {
+            1 => Ok(LevelItem::Value1),
+            2 => Ok(LevelItem::Value2),
+            value => Err(serde::de::Error::custom(format!("unknown LevelItem value: {value}"))),
+        }
+    }
+}
+
+impl serde::Serialize for LevelItem {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            LevelItem::Value1 => 1,
+            LevelItem::Value2 => 2,
+        };
+        serializer.serialize_i64(value)
+    }
+}
+
+/// Mode
+pub type Mode = Option<ModeItem>;
+
+/// ModeItem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum ModeItem {
+    #[serde(rename = "a")]
+    A,
+    #[serde(rename = "b")]
+    B,
+}
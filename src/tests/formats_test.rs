@@ -0,0 +1,140 @@
+use super::fixtures::{assert_generated, byte_shapes, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Item:
+      type: object
+      required: [id, data, small, big]
+      properties:
+        id:
+          type: string
+          format: uuid
+        link:
+          type: string
+          format: uri
+        data:
+          type: string
+          format: byte
+        thumbnail:
+          type: string
+          format: byte
+        small:
+          type: integer
+          format: int8
+        big:
+          type: integer
+          format: uint64
+        price:
+          type: number
+          format: decimal
+        address:
+          type: string
+          format: ipv4
+        email:
+          type: string
+          format: email
+"##;
+
+#[test]
+fn test_standard_formats() {
+    let filter = r#"
+standard_formats: true
+type_mappings:
+  - type: integer
+    format: uint64
+    rust_type: u128
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Item {
+    pub address: Option<std::net::Ipv4Addr>,
+    pub big: u128,
+    #[serde(with = "serde_with::As::<serde_with::base64::Base64>")]
+    pub data: Vec<u8>,
+    pub email: Option<String>,
+    pub id: uuid::Uuid,
+    pub link: Option<url::Url>,
+    pub price: Option<rust_decimal::Decimal>,
+    pub small: i8,
+    #[serde(with = "serde_with::As::<Option<serde_with::base64::Base64>>", default)]
+    pub thumbnail: Option<Vec<u8>>,
+}"#
+    ));
+}
+
+#[test]
+fn test_standard_formats_disabled() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Item {
+    pub address: Option<String>,
+    pub big: i32,
+    pub data: String,
+    pub email: Option<String>,
+    pub id: String,
+    pub link: Option<String>,
+    pub price: Option<f64>,
+    pub small: i32,
+    pub thumbnail: Option<String>,
+}"#
+    ));
+}
+
+const BYTE_SHAPES_SCHEMA: &str = r##"
+components:
+  schemas:
+    Blob:
+      type: string
+      format: byte
+    Blobs:
+      type: object
+      additionalProperties:
+        type: string
+        format: byte
+    BlobOrNumber:
+      oneOf:
+        - type: string
+          format: byte
+        - type: number
+    Attachment:
+      type: object
+      required: [parts]
+      properties:
+        parts:
+          type: object
+          additionalProperties:
+            type: string
+            format: byte
+        chunks:
+          type: array
+          items:
+            type: string
+            format: byte
+"##;
+
+#[test]
+fn test_byte_shapes() {
+    let openapi = OpenApi::from_str(BYTE_SHAPES_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true\nstandard_formats: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, byte_shapes::CODE);
+    assert!(s.contains(
+        r#"pub struct Blob(#[serde(with = "serde_with::As::<serde_with::base64::Base64>")] pub Vec<u8>);"#
+    ));
+    assert!(s.contains("mod _serde_with_as_serde_with_base64_base64_map {"));
+    round_trip::<byte_shapes::Blob>(r#""aGVsbG8=""#);
+    round_trip::<byte_shapes::Blobs>(r#"{"a":"aGVsbG8="}"#);
+    round_trip::<byte_shapes::BlobOrNumber>(r#""aGVsbG8=""#);
+    round_trip::<byte_shapes::BlobOrNumber>("1.5");
+    round_trip::<byte_shapes::Attachment>(r#"{"chunks":["aGVsbG8="],"parts":{"a":"aGVsbG8="}}"#);
+    round_trip::<byte_shapes::Attachment>(r#"{"parts":{}}"#);
+}
@@ -0,0 +1,176 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    BillingInvoice:
+      type: object
+      properties:
+        id:
+          type: string
+        details:
+          $ref: '#/components/schemas/BillingInternalDetails'
+    BillingInternalDetails:
+      type: object
+      properties:
+        note:
+          type: string
+    BillingInternalAudit:
+      type: object
+      properties:
+        id:
+          type: string
+    User:
+      type: object
+      properties:
+        name:
+          type: string
+        password_hash:
+          type: string
+        address:
+          type: object
+          properties:
+            city:
+              type: string
+            geo:
+              type: string
+    Order:
+      type: object
+      properties:
+        id:
+          type: string
+"##;
+
+fn filter(config: &str) -> (OpenApi, FilterConfig) {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(config).unwrap();
+    (openapi, config)
+}
+
+#[test]
+fn test_include_and_exclude() {
+    let (openapi, config) = filter(
+        r#"
+include:
+  BillingInvoice: [id]
+  BillingInternal*: "*"
+  User: "*"
+exclude:
+  BillingInternalAudit: "*"
+  User: [password_hash]
+  UserAddress: [geo]
+"#,
+    );
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct BillingInvoice {\n    pub id: Option<String>,\n}"));
+    assert!(s.contains("pub struct BillingInternalDetails {"));
+    assert!(!s.contains("BillingInternalAudit"));
+    assert!(s.contains(
+        "pub struct User {\n    pub address: Option<UserAddress>,\n    pub name: Option<String>,\n}"
+    ));
+    // the nested objects are filtered by the exclusions too
+    assert!(s.contains("pub struct UserAddress {\n    pub city: Option<String>,\n}"));
+    assert!(!s.contains("Order"));
+}
+
+#[test]
+fn test_exclude_dependencies() {
+    let (openapi, config) = filter(
+        r#"
+include:
+  BillingInvoice: "*"
+exclude:
+  BillingInvoice: [details]
+  BillingInternalDetails: "*"
+auto_include_dependencies: true
+"#,
+    );
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct BillingInvoice {\n    pub id: Option<String>,\n}"));
+    assert!(!s.contains("BillingInternal"));
+
+    // the excluded schema is not generated even if it is referenced
+    let (openapi, config) = filter(
+        r#"
+include:
+  BillingInvoice: "*"
+exclude:
+  BillingInternalDetails: "*"
+auto_include_dependencies: true
+"#,
+    );
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::MissingSchema { name, .. } if name == "BillingInternalDetails"
+    ));
+}
+
+#[test]
+fn test_ineffective_entries() {
+    let (openapi, config) = filter(
+        r#"
+include:
+  Billing*: "*"
+  BillingInternalAudit: "*"
+  User: [name]
+exclude:
+  BillingInternalAudit: "*"
+  Order: "*"
+  User: [password_hash]
+"#,
+    );
+    assert_eq!(
+        config.find_ineffective_entries(&openapi),
+        [
+            "`include` entry \"BillingInternalAudit\" has no effect, it is excluded",
+            "`exclude` entry \"Order\" has no effect, it matches no included schema",
+            "`exclude` entry \"User\" has no effect on property \"password_hash\", it is not included",
+        ]
+    );
+
+    let (openapi, config) = filter("exclude: {Order: '*'}");
+    assert!(config.find_ineffective_entries(&openapi).is_empty());
+}
+
+#[test]
+fn test_missing_schema_reported_once() {
+    let schema = r##"
+components:
+  schemas:
+    Invoice:
+      type: object
+      properties:
+        details:
+          $ref: '#/components/schemas/Details'
+    Payment:
+      type: object
+      properties:
+        audit:
+          $ref: '#/components/schemas/Audit'
+    Refund:
+      type: object
+      properties:
+        details:
+          $ref: '#/components/schemas/Details'
+    Audit:
+      type: object
+    Details:
+      type: object
+"##;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::from_str("exclude: {Audit: '*', Details: '*'}").unwrap();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    // every reference is reported once, even if the schema is referenced by
+    // several types
+    let errors: Vec<_> = err.iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        errors,
+        [
+            "#/components/schemas/Payment/properties/audit: reference to missing schema \"Audit\"",
+            "#/components/schemas/Invoice/properties/details: reference to missing schema \"Details\"",
+            "#/components/schemas/Refund/properties/details: reference to missing schema \"Details\"",
+        ]
+    );
+    assert!(err.iter().all(|e| matches!(e, Error::MissingSchema { .. })));
+}
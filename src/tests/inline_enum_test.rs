@@ -0,0 +1,250 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      required: [status]
+      properties:
+        status:
+          type: string
+          enum: [placed, delivered]
+        previousStatus:
+          type: string
+          enum: [delivered, placed]
+        flags:
+          type: array
+          items:
+            type: string
+            enum: [urgent, fragile]
+    Invoice:
+      type: object
+      required: [status]
+      properties:
+        status:
+          type: string
+          enum: [delivered, placed]
+"##;
+
+#[test]
+fn test_inline_enums() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the same enum is generated once for the schema
+    assert!(s.contains(
+        r#"pub struct Order {
+    pub flags: Option<Vec<OrderFlags>>,
+    #[serde(rename = "previousStatus")]
+    pub previous_status: Option<OrderPreviousStatus>,
+    pub status: OrderPreviousStatus,
+}"#
+    ));
+    assert!(s.contains(
+        r#"#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub enum OrderPreviousStatus {
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "placed")]
+    Placed,
+}"#
+    ));
+    assert!(s.contains("pub enum OrderFlags {"));
+    assert!(!s.contains("pub enum OrderStatus {"));
+    // the other schemas have their own enums
+    assert!(s.contains("pub struct Invoice {\n    pub status: InvoiceStatus,\n}"));
+    assert!(s.contains("pub enum InvoiceStatus {"));
+}
+
+#[test]
+fn test_shared_inline_enums() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+inline_type_names:
+  Order.status: Status
+  Invoice.status: Status
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the enums of different schemas are shared by the same name
+    assert!(s.contains("pub struct Invoice {\n    pub status: Status,\n}"));
+    assert!(s.contains("    pub status: Status,\n}"));
+    assert_eq!(s.matches("pub enum Status {").count(), 1);
+    assert!(s.contains("pub previous_status: Option<OrderPreviousStatus>,"));
+}
+
+#[test]
+fn test_inline_enum_variant_names() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Item:
+      type: object
+      required: [size, sign, style, mark, format]
+      properties:
+        size:
+          type: string
+          enum: ["1", "2", "10"]
+        sign:
+          type: string
+          enum: ["", "-"]
+        style:
+          type: string
+          enum: [foo_bar, fooBar, FooBar]
+        mark:
+          type: string
+          enum: ["+", "-"]
+        format:
+          type: string
+          enum: [a.b, image/png, "{id}", v1.0, é]
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub enum ItemSize {
+    #[serde(rename = "1")]
+    Value1,
+    #[serde(rename = "10")]
+    Value10,
+    #[serde(rename = "2")]
+    Value2,
+}"#
+    ));
+    assert!(s.contains(
+        r#"pub enum ItemSign {
+    #[serde(rename = "")]
+    Value,
+    #[serde(rename = "-")]
+    Minus,
+}"#
+    ));
+    // the same names get a number
+    assert!(s.contains(
+        r#"pub enum ItemStyle {
+    #[serde(rename = "FooBar")]
+    FooBar3,
+    #[serde(rename = "fooBar")]
+    FooBar2,
+    #[serde(rename = "foo_bar")]
+    FooBar,
+}"#
+    ));
+    // the values without letters and digits are named like the numbers
+    assert!(s.contains(
+        r#"pub enum ItemMark {
+    #[serde(rename = "+")]
+    Value,
+    #[serde(rename = "-")]
+    Minus,
+}"#
+    ));
+    // the other symbols and the non-ASCII letters separate the words
+    assert!(s.contains(
+        r#"pub enum ItemFormat {
+    #[serde(rename = "a.b")]
+    AB,
+    #[serde(rename = "image/png")]
+    ImagePng,
+    #[serde(rename = "v1.0")]
+    V10,
+    #[serde(rename = "{id}")]
+    Id,
+    #[serde(rename = "é")]
+    Value,
+}"#
+    ));
+}
+
+#[test]
+fn test_enum_values_of_another_type() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Item:
+      type: object
+      properties:
+        code:
+          type: string
+          enum: [a, 1]
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let (s, warnings) = generate_openapi_types_with_warnings(openapi, config).unwrap();
+
+    // the value cannot be listed, so the enum is not generated
+    assert!(s.contains("    pub code: Option<String>,\n"));
+    assert_eq!(
+        warnings,
+        [
+            "#/components/schemas/Item/properties/code: the values of the enum are not all \
+            of type \"string\", it is read as the type itself"
+        ]
+    );
+}
+
+#[test]
+fn test_inline_enums_of_nested_objects() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      required: [status]
+      properties:
+        status:
+          type: string
+          enum: [placed, delivered]
+        shippingAddress:
+          type: object
+          properties:
+            status:
+              type: string
+              enum: [placed, delivered]
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the top-level property is not typed after the nested one
+    assert!(s.contains("    pub status: OrderStatus,\n}"));
+    assert!(s.contains("    pub status: Option<OrderShippingAddressStatus>,\n}"));
+    assert!(s.contains("pub enum OrderStatus {"));
+    assert!(s.contains("pub enum OrderShippingAddressStatus {"));
+}
+
+#[test]
+fn test_root_enum_variant_names() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Status:
+      type: string
+      enum: [in-progress, Done, "-"]
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the symbols are spelled out only when there is no other name, and
+    // the value is renamed only when it differs from the name
+    assert!(s.contains(
+        r#"pub enum Status {
+    #[serde(rename = "-")]
+    Minus,
+    Done,
+    #[serde(rename = "in-progress")]
+    InProgress,
+}"#
+    ));
+}
@@ -0,0 +1,215 @@
+use super::fixtures::{assert_generated, nullable_enums, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Priority:
+      type: integer
+      enum: [1, 2, 3]
+      x-enum-varnames: [Low, Normal, High]
+    Level:
+      type: integer
+      enum: [-1, 0, 10]
+    Task:
+      type: object
+      required: [flag]
+      properties:
+        kind:
+          type: integer
+          enum: [2, 1]
+          x-enumNames: [Two, One]
+        flag:
+          type: boolean
+          enum: [true]
+        ratio:
+          type: number
+          enum: [0.5, 1.5]
+        version:
+          const: 2
+"##;
+
+#[test]
+fn test_integer_enums() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(i64)]
+pub enum Priority {
+    Low = 1,
+    Normal = 2,
+    High = 3,
+}"#
+    ));
+    assert!(s.contains(
+        r#"impl<'de> serde::Deserialize<'de> for Priority {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <i64 as serde::Deserialize>::deserialize(deserializer)? {
+            1 => Ok(Priority::Low),
+            2 => Ok(Priority::Normal),
+            3 => Ok(Priority::High),
+            value => Err(serde::de::Error::custom(format!("unknown Priority value: {value}"))),
+        }
+    }
+}"#
+    ));
+    assert!(!s.contains("impl serde::Serialize"));
+
+    // without the names
+    assert!(s.contains(
+        "pub enum Level {\n    ValueMinus1 = -1,\n    Value0 = 0,\n    Value10 = 10,\n}"
+    ));
+
+    // inline enums
+    assert!(s.contains("pub kind: Option<TaskKind>,"));
+    assert!(s.contains("pub enum TaskKind {\n    One = 1,\n    Two = 2,\n}"));
+    assert!(s.contains("pub version: Option<TaskVersion>,"));
+    assert!(s.contains("pub enum TaskVersion {\n    Value2 = 2,\n}"));
+
+    // other values cannot be listed in an enum
+    assert!(s.contains("pub flag: bool,"));
+    assert!(s.contains("pub ratio: Option<f64>,"));
+}
+
+#[test]
+fn test_integer_enums_serialize() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"impl serde::Serialize for Priority {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            Priority::Low => 1,
+            Priority::Normal => 2,
+            Priority::High => 3,
+        };
+        serializer.serialize_i64(value)
+    }
+}"#
+    ));
+}
+
+#[test]
+fn test_integer_enum_names_and_null() {
+    let schema = r#"
+components:
+  schemas:
+    Job:
+      type: object
+      required: [state]
+      properties:
+        state:
+          type: integer
+          enum: [1, 2, 3, 4, 5, null]
+          x-enum-varnames: [in-progress, done, Done, 1st, self]
+"#;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // `null` in the list makes the field optional
+    assert!(s.contains("pub state: Option<JobState>,"));
+    // the names are translated and made unique, the invalid ones are replaced
+    assert!(s.contains(
+        r#"pub enum JobState {
+    InProgress = 1,
+    Done = 2,
+    Done2 = 3,
+    Value4 = 4,
+    Value5 = 5,
+}"#
+    ));
+}
+
+const NULLABLE_SCHEMA: &str = r##"
+components:
+  schemas:
+    Mode:
+      type: [string, "null"]
+      enum: [a, b, null]
+    Level:
+      type: integer
+      nullable: true
+      enum: [1, 2]
+"##;
+
+#[test]
+fn test_nullable_root_enums() {
+    let openapi = OpenApi::from_str(NULLABLE_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, nullable_enums::CODE);
+    // the enumeration cannot be null, so the schema is an optional alias
+    assert!(s.contains("pub type Mode = Option<ModeItem>;"));
+    assert!(s.contains("pub enum ModeItem {"));
+    assert!(s.contains("pub type Level = Option<LevelItem>;"));
+    assert!(s.contains("pub enum LevelItem {"));
+
+    assert_eq!(round_trip::<nullable_enums::Mode>("null"), None);
+    assert_eq!(
+        round_trip::<nullable_enums::Mode>(r#""a""#),
+        Some(nullable_enums::ModeItem::A)
+    );
+    assert_eq!(round_trip::<nullable_enums::Level>("null"), None);
+    assert_eq!(
+        round_trip::<nullable_enums::Level>("2"),
+        Some(nullable_enums::LevelItem::Value2)
+    );
+}
+
+#[test]
+fn test_root_enums_with_awkward_values() {
+    let schema = r#"
+components:
+  schemas:
+    MediaType:
+      type: string
+      enum: [image/png, "1.5", "+1"]
+    Version:
+      type: [string, "null"]
+      enum: [v1.0, é, null]
+    Ratio:
+      type: integer
+      enum: [1, 1.5]
+"#;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::default();
+    let (s, warnings) = generate_openapi_types_with_warnings(openapi, config).unwrap();
+
+    // like the integers, every string value gets a name
+    assert!(!s.contains("= String;"));
+    // the values that are not integers cannot be listed
+    assert!(s.contains("pub type Ratio = i32;"));
+    assert_eq!(
+        warnings,
+        [
+            "#/components/schemas/Ratio: the values of the enum are not all of type \
+            \"integer\", it is read as the type itself"
+        ]
+    );
+    assert!(s.contains(
+        r#"pub enum MediaType {
+    #[serde(rename = "+1")]
+    Value1,
+    #[serde(rename = "1.5")]
+    Value15,
+    #[serde(rename = "image/png")]
+    ImagePng,
+}"#
+    ));
+    assert!(s.contains("pub type Version = Option<VersionItem>;"));
+    assert!(s.contains(
+        r#"pub enum VersionItem {
+    #[serde(rename = "v1.0")]
+    V10,
+    #[serde(rename = "é")]
+    Value,
+}"#
+    ));
+}
@@ -1,5 +1,31 @@
+mod additional_properties_test;
+mod allof_test;
 mod anyobject_test;
+mod anyof_test;
 mod auto_include_deps_test;
+mod date_time_test;
+mod discriminator_test;
+mod double_option_test;
+mod error_test;
+mod external_refs_test;
+mod filter_validation_test;
+mod fixtures;
+mod formats_test;
+mod include_exclude_test;
+mod inline_enum_test;
+mod integer_enum_test;
+mod nested_object_test;
 mod oneof_test;
+mod openapi31_test;
+mod operations_test;
 mod optional_property_ref_in_component_test;
+mod patterns_test;
+mod recursive_test;
+mod root_union_test;
+mod serialize_test;
+mod swagger2_test;
+mod tags_test;
+mod temp_dir;
+mod type_mapping_test;
 mod typealias_test;
+mod unknown_enum_test;
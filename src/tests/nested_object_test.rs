@@ -0,0 +1,119 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Address:
+      type: object
+      properties:
+        city:
+          type: string
+    Order:
+      type: object
+      required: [shippingAddress]
+      properties:
+        shippingAddress:
+          type: object
+          properties:
+            street:
+              type: string
+            geo:
+              type: object
+              properties:
+                lat:
+                  type: number
+        lines:
+          type: array
+          items:
+            type: object
+            properties:
+              billing:
+                $ref: '#/components/schemas/Address'
+"##;
+
+#[test]
+fn test_nested_objects() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Order {
+    pub lines: Option<Vec<OrderLines>>,
+    #[serde(rename = "shippingAddress")]
+    pub shipping_address: OrderShippingAddress,
+}"#
+    ));
+    assert!(s.contains(
+        r#"pub struct OrderShippingAddress {
+    pub geo: Option<OrderShippingAddressGeo>,
+    pub street: Option<String>,
+}"#
+    ));
+    assert!(s.contains("pub struct OrderShippingAddressGeo {\n    pub lat: Option<f64>,\n}"));
+    assert!(s.contains("pub struct OrderLines {\n    pub billing: Option<Address>,\n}"));
+}
+
+#[test]
+fn test_nested_object_names() {
+    let filter = r#"
+include:
+  Order: "*"
+auto_include_dependencies: true
+inline_type_names:
+  Order.shippingAddress: ShippingAddress
+  ShippingAddress.geo: Geo
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub shipping_address: ShippingAddress,"));
+    assert!(s.contains("pub struct ShippingAddress {"));
+    assert!(s.contains("pub geo: Option<Geo>,"));
+    assert!(s.contains("pub struct Geo {"));
+    // found through the nested object
+    assert!(s.contains("pub struct Address {"));
+}
+
+#[test]
+fn test_nested_object_name_taken() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      required: [shippingAddress]
+      properties:
+        shippingAddress:
+          type: object
+          properties:
+            street:
+              type: string
+    OrderShippingAddress:
+      type: object
+      properties:
+        id:
+          type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    // the name is taken by the schema
+    assert!(s.contains("pub shipping_address: OrderShippingAddress2,"));
+    assert!(s.contains("pub struct OrderShippingAddress2 {\n    pub street: Option<String>,\n}"));
+    assert!(s.contains("pub struct OrderShippingAddress {\n    pub id: Option<String>,\n}"));
+
+    // the name from the filter cannot be changed
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config =
+        FilterConfig::from_str("inline_type_names:\n  Order.shippingAddress: OrderShippingAddress")
+            .unwrap();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Unsupported { location, .. }
+            if location == "#/components/schemas/Order/properties/shippingAddress"
+    ));
+}
@@ -17,23 +17,30 @@ components:
           type: number
     Mixed:
       type: object
+      required: [main]
       properties:
+        main:
+          oneOf:
+            - $ref: '#/components/schemas/First'
+            - $ref: '#/components/schemas/Second'
         firstOrSecond:
           oneOf:
             - $ref: '#/components/schemas/First'
             - $ref: '#/components/schemas/Second'
 "##;
 
-    let openapi = OpenApi::from_str(SCHEMA);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
     let config = FilterConfig::default();
     let s = generate_openapi_types(openapi, config).unwrap();
 
-    // check "Mixed" is generated right
+    // check "Mixed" is generated right, the property that is not required
+    // is optional
     assert!(s.contains(
         r#"#[derive(Debug, Clone, Deserialize)]
 pub struct Mixed {
     #[serde(rename = "firstOrSecond")]
-    pub first_or_second: _UnionFirstOrSecond,
+    pub first_or_second: Option<_UnionFirstOrSecond>,
+    pub main: _UnionFirstOrSecond,
 }"#
     ));
 
@@ -0,0 +1,163 @@
+use crate::openapi::Schema;
+use crate::*;
+
+#[test]
+fn test_type_arrays_and_null() {
+    const SCHEMA: &str = r##"
+openapi: 3.1.0
+components:
+  schemas:
+    Owner:
+      type: object
+      required: [name]
+      properties:
+        name:
+          type: string
+    Pet:
+      type: object
+      required: [name, tag, owner, age]
+      properties:
+        name:
+          type: string
+        tag:
+          type: [string, "null"]
+        owner:
+          oneOf:
+            - $ref: '#/components/schemas/Owner'
+            - type: null
+        age:
+          type: [integer, string]
+          description: Age in years
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub name: String,"));
+    assert!(s.contains("pub tag: Option<String>,"));
+    assert!(s.contains("pub owner: Option<Owner>,"));
+    assert!(s.contains("/// Age in years\n    pub age: _UnionIntegerOrString,"));
+}
+
+#[test]
+fn test_const_and_defs() {
+    const SCHEMA: &str = r##"
+openapi: 3.1.0
+components:
+  schemas:
+    Kind:
+      const: dog
+    Pet:
+      type: object
+      required: [kind, collar]
+      properties:
+        kind:
+          $ref: '#/components/schemas/Kind'
+        collar:
+          $ref: '#/components/schemas/Pet/$defs/Collar'
+      $defs:
+        Collar:
+          type: object
+          properties:
+            color:
+              type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub enum Kind {\n    #[serde(rename = \"dog\")]\n    Dog,\n}"));
+    assert!(s.contains("pub collar: Collar,"));
+    assert!(s.contains("pub struct Collar {"));
+}
+
+#[test]
+fn test_defs_of_several_schemas() {
+    const SCHEMA: &str = r##"
+openapi: 3.1.0
+paths:
+  /pets:
+    get:
+      parameters:
+        - name: version
+          in: query
+          schema:
+            const: v1
+      responses:
+        200:
+          description: The collar
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet/properties/collar/$defs/Buckle'
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [color, collar]
+      properties:
+        color:
+          $ref: '#/$defs/Color'
+        collar:
+          type: object
+          required: [buckle, size]
+          properties:
+            buckle:
+              $ref: '#/components/schemas/Pet/properties/collar/$defs/Buckle'
+            size:
+              $ref: '#/components/schemas/Pet/$defs/Color/$defs/Size'
+          $defs:
+            Buckle:
+              type: object
+              properties:
+                metal:
+                  type: string
+      $defs:
+        Color:
+          type: string
+          enum: [red]
+          $defs:
+            Size:
+              type: integer
+    Toy:
+      type: object
+      required: [color]
+      properties:
+        color:
+          $ref: '#/$defs/Color'
+      $defs:
+        Color:
+          type: integer
+"##;
+
+    let mut openapi = OpenApi::from_str(SCHEMA).unwrap();
+    // `const` is an enumeration in the schemas of the operations too
+    let parameter = &openapi.paths["/pets"].get.as_ref().unwrap().parameters[0];
+    assert!(matches!(
+        &parameter.schema,
+        Some(Schema::Typed { enum_items: Some(items), .. }) if items == &["v1"]
+    ));
+    // the references of the operations are renamed as well
+    let response = &openapi.paths["/pets"].get.as_ref().unwrap().responses["200"];
+    assert!(matches!(
+        &response.content["application/json"].schema,
+        Some(Schema::Ref { ref_ }) if ref_ == "#/components/schemas/Buckle"
+    ));
+    openapi.paths.clear();
+
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    // `#/$defs/Color` refers to the definition of the schema it is written
+    // in, the name of the second one is qualified by its owner
+    assert!(s.contains("pub enum Color {"));
+    assert!(s.contains("pub color: Color,"));
+    assert!(s.contains("pub type ToyColor = i32;"));
+    assert!(s.contains("pub struct Toy {\n    pub color: ToyColor,\n}"));
+    // the definitions of the nested schemas and of the definitions
+    assert!(s.contains("pub buckle: Buckle,"));
+    assert!(s.contains("pub struct Buckle {"));
+    assert!(s.contains("pub size: Size,"));
+    assert!(s.contains("pub type Size = i32;"));
+}
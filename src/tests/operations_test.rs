@@ -0,0 +1,266 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+paths:
+  x-internal: true
+  /pets/{id}:
+    parameters:
+      - name: id
+        in: path
+        schema:
+          $ref: '#/components/schemas/PetId'
+    get:
+      operationId: getPet
+      responses:
+        200:
+          description: The pet
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Pet'
+        default:
+          $ref: '#/components/responses/Error'
+  /orders:
+    post:
+      operationId: createOrder
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                order:
+                  $ref: '#/components/schemas/NewOrder'
+      responses:
+        201:
+          description: Created
+components:
+  responses:
+    Error:
+      description: Error
+      content:
+        application/json:
+          schema:
+            $ref: '#/components/schemas/Error'
+  schemas:
+    PetId:
+      type: integer
+      format: int64
+    Pet:
+      type: object
+      required: [id]
+      properties:
+        id:
+          $ref: '#/components/schemas/PetId'
+        owner:
+          $ref: '#/components/schemas/Owner'
+    Owner:
+      type: object
+      properties:
+        name:
+          type: string
+        email:
+          type: string
+    Error:
+      type: object
+      properties:
+        message:
+          type: string
+    NewOrder:
+      type: object
+      properties:
+        petId:
+          $ref: '#/components/schemas/PetId'
+    Unused:
+      type: object
+      properties:
+        id:
+          type: string
+"##;
+
+#[test]
+fn test_operations() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include_operations:
+  - getPet
+include:
+  Owner: [name]
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    // the schemas of the parameters and the responses, including the
+    // referenced responses, with their dependencies
+    assert!(s.contains("pub type PetId = i64;"));
+    assert!(s.contains("pub struct Pet {"));
+    assert!(s.contains("pub struct Error {"));
+    // the schemas listed in the filter keep their filters
+    assert!(s.contains("pub struct Owner {\n    pub name: Option<String>,\n}"));
+    assert!(!s.contains("NewOrder"));
+    assert!(!s.contains("Unused"));
+}
+
+#[test]
+fn test_operations_by_method_and_path() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include_operations:
+  - post /orders
+"#,
+    )
+    .unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    // the schemas referenced by the inline request body
+    assert!(s.contains("pub struct NewOrder {"));
+    assert!(s.contains("pub type PetId = i64;"));
+    assert!(!s.contains("pub struct Pet {"));
+}
+
+#[test]
+fn test_unknown_operations() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include_operations:
+  - getPet
+  - deletePet
+  - PUT /orders
+"#,
+    )
+    .unwrap();
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let operations: Vec<_> = err
+        .iter()
+        .map(|e| match e {
+            Error::UnknownOperation { operation } => operation.as_str(),
+            _ => panic!("unexpected error: {e}"),
+        })
+        .collect();
+    assert_eq!(operations, ["deletePet", "PUT /orders"]);
+}
+
+#[test]
+fn test_unknown_operations_with_other_errors() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(
+        r#"
+include:
+  /(/: "*"
+include_operations:
+  - deletePet
+"#,
+    )
+    .unwrap();
+    // all the problems are reported at once
+    let err = generate_openapi_types(openapi, config).unwrap_err();
+    let errors: Vec<_> = err.iter().collect();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], Error::InvalidPattern { pattern, .. } if pattern == "/(/"));
+    assert!(matches!(errors[1], Error::UnknownOperation { operation } if operation == "deletePet"));
+}
+
+#[test]
+fn test_operations_swagger2() {
+    const SCHEMA: &str = r##"
+swagger: "2.0"
+paths:
+  /pets:
+    post:
+      operationId: addPet
+      parameters:
+        - name: body
+          in: body
+          schema:
+            $ref: '#/definitions/NewPet'
+      responses:
+        200:
+          description: The pet
+          schema:
+            type: array
+            items:
+              $ref: '#/definitions/Pet'
+definitions:
+  NewPet:
+    type: object
+    properties:
+      name:
+        type: string
+  Pet:
+    type: object
+    properties:
+      id:
+        type: integer
+  Unused:
+    type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("include_operations: [addPet]").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    assert!(s.contains("pub struct NewPet {"));
+    assert!(s.contains("pub struct Pet {"));
+    assert!(!s.contains("Unused"));
+}
+
+#[test]
+fn test_operations_swagger2_shared() {
+    const SCHEMA: &str = r##"
+swagger: "2.0"
+paths:
+  /pets/search:
+    post:
+      operationId: searchPets
+      parameters:
+        - $ref: '#/parameters/Query'
+      responses:
+        200:
+          description: The pets
+          schema:
+            type: array
+            items:
+              $ref: '#/definitions/Pet'
+        404:
+          $ref: '#/responses/NotFound'
+parameters:
+  Query:
+    name: query
+    in: body
+    schema:
+      $ref: '#/definitions/Query'
+responses:
+  NotFound:
+    description: Not found
+    schema:
+      $ref: '#/definitions/Problem'
+definitions:
+  Query:
+    type: object
+    properties:
+      name:
+        type: string
+  Pet:
+    type: object
+    properties:
+      id:
+        type: integer
+  Problem:
+    type: object
+    properties:
+      title:
+        type: string
+  Unused:
+    type: string
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("include_operations: [searchPets]").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+    // the parameters and the responses are taken as the components
+    assert!(s.contains("pub struct Query {"));
+    assert!(s.contains("pub struct Pet {"));
+    assert!(s.contains("pub struct Problem {"));
+    assert!(!s.contains("Unused"));
+}
@@ -30,7 +30,7 @@ components:
           description: Origin
 "##;
 
-    let openapi = OpenApi::from_str(SCHEMA);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
     let config = FilterConfig::default();
 
     let openapi_types = generate_openapi_types(openapi, config).unwrap();
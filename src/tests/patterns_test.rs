@@ -0,0 +1,112 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    BillingInvoice:
+      type: object
+      properties:
+        id:
+          type: string
+        amount:
+          type: number
+        self_links:
+          type: string
+    BillingRefund:
+      type: object
+      properties:
+        id:
+          type: string
+        reason:
+          type: string
+        refund_links:
+          type: string
+    AcmeUserDto:
+      type: object
+      properties:
+        id:
+          type: string
+    Order:
+      type: object
+      properties:
+        id:
+          type: string
+"##;
+
+fn generate(config: &str) -> Result<String, Error> {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(config).unwrap();
+    generate_openapi_types(openapi, config)
+}
+
+#[test]
+fn test_glob_patterns() {
+    let s = generate(
+        r#"
+include:
+  Billing*: ["id", "*_links"]
+"#,
+    )
+    .unwrap();
+    assert!(s.contains(
+        "pub struct BillingInvoice {\n    pub id: Option<String>,\n    pub self_links: Option<String>,\n}"
+    ));
+    assert!(s.contains(
+        "pub struct BillingRefund {\n    pub id: Option<String>,\n    pub refund_links: Option<String>,\n}"
+    ));
+    assert!(!s.contains("Order"));
+
+    let s = generate(
+        r#"
+exclude:
+  "?illing*": "*"
+"#,
+    )
+    .unwrap();
+    assert!(!s.contains("Billing"));
+    assert!(s.contains("pub struct Order {"));
+}
+
+#[test]
+fn test_pattern_precedence() {
+    // the exact name takes precedence over the pattern, and the longer
+    // pattern over the shorter one
+    let s = generate(
+        r#"
+include:
+  "*": [id]
+  Billing*: [reason]
+  BillingInvoice: "*"
+"#,
+    )
+    .unwrap();
+    assert!(s.contains("pub struct BillingInvoice {\n    pub amount: Option<f64>,"));
+    assert!(s.contains("pub struct BillingRefund {\n    pub reason: Option<String>,\n}"));
+    assert!(s.contains("pub struct Order {\n    pub id: Option<String>,\n}"));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_regex_patterns() {
+    let s = generate(
+        r#"
+include:
+  /^Acme.+Dto$/: "*"
+  Order: ["/^i/"]
+"#,
+    )
+    .unwrap();
+    assert!(s.contains("pub struct AcmeUserDto {"));
+    assert!(s.contains("pub struct Order {\n    pub id: Option<String>,\n}"));
+    assert!(!s.contains("Billing"));
+
+    let err = generate("include: {'/(/': '*'}").unwrap_err();
+    assert!(matches!(err, Error::InvalidPattern { pattern, .. } if pattern == "/(/"));
+}
+
+#[cfg(not(feature = "regex"))]
+#[test]
+fn test_regex_patterns_without_feature() {
+    let err = generate("include: {'/^Acme.+Dto$/': '*'}").unwrap_err();
+    assert!(matches!(err, Error::InvalidPattern { pattern, .. } if pattern == "/^Acme.+Dto$/"));
+}
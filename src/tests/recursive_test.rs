@@ -0,0 +1,89 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Comment:
+      type: object
+      properties:
+        text:
+          type: string
+        parent:
+          $ref: '#/components/schemas/Comment'
+    TreeNode:
+      type: object
+      properties:
+        children:
+          type: array
+          items:
+            $ref: '#/components/schemas/TreeNode'
+        attributes:
+          type: object
+          additionalProperties:
+            $ref: '#/components/schemas/TreeNode'
+    Department:
+      type: object
+      required: [head]
+      properties:
+        head:
+          $ref: '#/components/schemas/Employee'
+    Employee:
+      type: object
+      properties:
+        department:
+          $ref: '#/components/schemas/Department'
+    Box:
+      type: object
+      properties:
+        inner:
+          $ref: '#/components/schemas/Box'
+"##;
+
+#[test]
+fn test_recursive_types() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub parent: Option<std::boxed::Box<Comment>>,"));
+    // arrays and maps are already on the heap
+    assert!(s.contains("pub children: Option<Vec<TreeNode>>,"));
+    assert!(s.contains("HashMap<String, TreeNode>"));
+    // only one edge of the cycle is boxed
+    assert!(s.contains("pub head: Employee,"));
+    assert!(s.contains("pub department: Option<std::boxed::Box<Department>>,"));
+    // the schema name does not shadow the standard type
+    assert!(s.contains("pub inner: Option<std::boxed::Box<Box>>,"));
+}
+
+#[test]
+fn test_recursion_through_alias() {
+    const SCHEMA: &str = r##"
+components:
+  schemas:
+    Node:
+      type: object
+      properties:
+        parent:
+          $ref: '#/components/schemas/Parent'
+    Parent:
+      $ref: '#/components/schemas/Node'
+    Wrapper:
+      type: object
+      required: [parent]
+      properties:
+        parent:
+          $ref: '#/components/schemas/Parent'
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the cycle is found at the alias, but the field is boxed instead, so
+    // the other uses of the alias are not boxed
+    assert!(s.contains("pub type Parent = Node;"));
+    assert!(s.contains("pub struct Node {\n    pub parent: Option<std::boxed::Box<Parent>>,\n}"));
+    assert!(s.contains("pub struct Wrapper {\n    pub parent: Parent,\n}"));
+    assert_eq!(s.matches("std::boxed::Box").count(), 1);
+}
@@ -0,0 +1,111 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        lives:
+          type: integer
+    Dog:
+      type: object
+      properties:
+        bark:
+          type: string
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+    Id:
+      oneOf:
+        - type: integer
+          format: int64
+        - type: string
+        - type: array
+          items:
+            type: string
+"##;
+
+#[test]
+fn test_root_union() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"/// Pet
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Pet {
+    Cat(Cat),
+    Dog(Dog),
+}"#
+    ));
+    assert!(s.contains(
+        r#"pub enum Id {
+    Integer(i64),
+    String(String),
+    StringList(Vec<String>),
+}"#
+    ));
+    assert!(!s.contains("_Union"));
+}
+
+#[test]
+fn test_root_union_variant_names() {
+    let filter = r#"
+union_variant_names:
+  Pet.Cat: Kitty
+  Id.StringList: Many
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("pub enum Pet {\n    Kitty(Cat),\n    Dog(Dog),\n}"));
+    assert!(s.contains("    Many(Vec<String>),\n"));
+}
+
+#[test]
+fn test_nullable_root_union() {
+    let schema = r##"
+components:
+  schemas:
+    Cat:
+      type: object
+      properties:
+        lives:
+          type: integer
+    Dog:
+      type: object
+      properties:
+        bark:
+          type: string
+    Pet:
+      oneOf:
+        - $ref: '#/components/schemas/Cat'
+        - $ref: '#/components/schemas/Dog'
+        - type: 'null'
+    Id:
+      nullable: true
+      anyOf:
+        - type: integer
+          format: int64
+        - type: string
+"##;
+    let filter = r#"
+union_variant_names:
+  Pet.Cat: Kitty
+"#;
+    let openapi = OpenApi::from_str(schema).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the enumeration is named like a nested type, the schema is optional
+    assert!(s.contains("pub type Pet = Option<PetItem>;"));
+    assert!(s.contains("pub enum PetItem {\n    Kitty(Cat),\n    Dog(Dog),\n}"));
+    assert!(s.contains("pub type Id = Option<IdItem>;"));
+    assert!(s.contains("pub enum IdItem {\n    Integer(i64),\n    String(String),\n}"));
+    assert!(!s.contains("_Union"));
+}
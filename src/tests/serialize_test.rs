@@ -0,0 +1,110 @@
+use super::fixtures::{assert_generated, required_nullable, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      required: [id]
+      properties:
+        id:
+          type: integer
+        comment:
+          type: string
+        status:
+          type: string
+          enum: [new, paid]
+        createdAt:
+          type: string
+          format: date-time
+"##;
+
+#[test]
+fn test_serialize() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("use serde::{Deserialize, Serialize};"));
+    assert!(s.contains("#[derive(Debug, Clone, Deserialize, Serialize)]\npub struct Order {"));
+    assert!(s.contains(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]\npub enum OrderStatus {"
+    ));
+    assert!(s.contains(
+        r#"    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+"#
+    ));
+    assert!(s.contains(
+        r#"    #[serde(rename = "createdAt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub created_at: Option<time::OffsetDateTime>,
+"#
+    ));
+    assert!(s.contains("    pub id: i32,\n"));
+}
+
+#[test]
+fn test_serialize_in_derives() {
+    let filter = r#"
+struct_derives: [Debug, Deserialize, Serialize]
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("use serde::{Deserialize, Serialize};"));
+    assert!(s.contains("#[derive(Debug, Deserialize, Serialize)]\npub struct Order {"));
+    // the enums are serialized too, as they are the field types
+    assert!(s.contains("Ord, Deserialize, Serialize)]\npub enum OrderStatus {"));
+    assert!(s.contains("skip_serializing_if"));
+}
+
+#[test]
+fn test_deserialize_only() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("use serde::Deserialize;\n"));
+    assert!(!s.contains("Serialize"));
+    assert!(!s.contains("skip_serializing_if"));
+}
+
+const REQUIRED_NULLABLE_SCHEMA: &str = r##"
+components:
+  schemas:
+    Patch:
+      type: object
+      required: [email]
+      properties:
+        email:
+          type: string
+          nullable: true
+        phone:
+          type: string
+          nullable: true
+"##;
+
+#[test]
+fn test_serialize_required_nullable() {
+    let openapi = OpenApi::from_str(REQUIRED_NULLABLE_SCHEMA).unwrap();
+    let config = FilterConfig::from_str("serialize: true").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, required_nullable::CODE);
+    assert!(s.contains(
+        r#"pub struct Patch {
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}"#
+    ));
+
+    // the required null is written, the absent one is skipped
+    let patch = round_trip::<required_nullable::Patch>(r#"{"email":null}"#);
+    assert!(patch.email.is_none() && patch.phone.is_none());
+    round_trip::<required_nullable::Patch>(r#"{"email":"a@b.c","phone":"1"}"#);
+}
@@ -0,0 +1,92 @@
+use crate::*;
+
+#[test]
+fn test_swagger2_definitions() {
+    const SCHEMA: &str = r##"
+{
+  "swagger": "2.0",
+  "info": { "title": "Swagger Petstore", "version": "1.0.0" },
+  "paths": {},
+  "definitions": {
+    "Pet": {
+      "type": "object",
+      "required": ["id", "category"],
+      "properties": {
+        "id": { "type": "integer", "format": "int64" },
+        "category": { "$ref": "#/definitions/Category" },
+        "tags": {
+          "type": "array",
+          "items": { "$ref": "#/definitions/Tag" }
+        }
+      }
+    },
+    "Category": {
+      "type": "object",
+      "properties": { "name": { "type": "string" } }
+    },
+    "Tag": {
+      "type": "string",
+      "enum": ["new", "sold"]
+    }
+  }
+}
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Pet {
+    pub category: Category,
+    pub id: i64,
+    pub tags: Option<Vec<Tag>>,
+}"#
+    ));
+    assert!(s.contains("pub struct Category {"));
+    assert!(s.contains("pub enum Tag {"));
+}
+
+#[test]
+fn test_swagger2_discriminator() {
+    const SCHEMA: &str = r##"
+{
+  "swagger": "2.0",
+  "info": { "title": "Swagger Petstore", "version": "1.0.0" },
+  "paths": {},
+  "definitions": {
+    "Pet": {
+      "type": "object",
+      "discriminator": "petType",
+      "required": ["name", "petType"],
+      "properties": {
+        "name": { "type": "string" },
+        "petType": { "type": "string" }
+      }
+    },
+    "Cat": {
+      "allOf": [
+        { "$ref": "#/definitions/Pet" },
+        {
+          "type": "object",
+          "properties": { "huntingSkill": { "type": "string" } }
+        }
+      ]
+    }
+  }
+}
+"##;
+
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"pub struct Pet {
+    pub name: String,
+    #[serde(rename = "petType")]
+    pub pet_type: String,
+}"#
+    ));
+    assert!(s.contains("pub struct Cat {"));
+}
@@ -0,0 +1,102 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+paths:
+  /invoices:
+    get:
+      tags: [billing]
+      responses:
+        200:
+          description: Invoices
+          content:
+            application/json:
+              schema:
+                type: array
+                items:
+                  $ref: '#/components/schemas/Invoice'
+  /invoices/{id}/refund:
+    post:
+      tags: [billing, admin]
+      responses:
+        200:
+          description: Refund
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Refund'
+  /items:
+    get:
+      tags: [inventory]
+      responses:
+        200:
+          description: Items
+          content:
+            application/json:
+              schema:
+                $ref: '#/components/schemas/Item'
+components:
+  schemas:
+    Invoice:
+      type: object
+      properties:
+        amount:
+          $ref: '#/components/schemas/Money'
+    Refund:
+      type: object
+      properties:
+        amount:
+          $ref: '#/components/schemas/Money'
+    Money:
+      type: object
+      properties:
+        value:
+          type: string
+    Item:
+      type: object
+      properties:
+        name:
+          type: string
+"##;
+
+fn generate(config: &str) -> String {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(config).unwrap();
+    generate_openapi_types(openapi, config).unwrap()
+}
+
+#[test]
+fn test_include_tags() {
+    let s = generate("include_tags: [billing]");
+    assert!(s.contains("pub struct Invoice {"));
+    assert!(s.contains("pub struct Refund {"));
+    assert!(s.contains("pub struct Money {"));
+    assert!(!s.contains("Item"));
+}
+
+#[test]
+fn test_exclude_tags() {
+    // the operations with any of the excluded tags are skipped
+    let s = generate("include_tags: [billing]\nexclude_tags: [admin]");
+    assert!(s.contains("pub struct Invoice {"));
+    assert!(!s.contains("Refund"));
+    assert!(!s.contains("Item"));
+
+    // without `include_tags`, all the other operations are selected
+    let s = generate("exclude_tags: [billing]");
+    assert!(s.contains("pub struct Item {"));
+    assert!(!s.contains("Invoice"));
+    assert!(!s.contains("Money"));
+}
+
+#[test]
+fn test_tags_with_operations() {
+    let s = generate(
+        r#"
+include_tags: [inventory]
+include_operations: ['POST /invoices/{id}/refund']
+"#,
+    );
+    assert!(s.contains("pub struct Item {"));
+    assert!(s.contains("pub struct Refund {"));
+    assert!(!s.contains("Invoice"));
+}
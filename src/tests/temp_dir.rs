@@ -0,0 +1,26 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A temporary directory, unique for the test and the process, removed with
+/// its content when the value is dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(test_name: &str) -> Self {
+        let dir_name = format!("openapi_type_picker_{test_name}_{}", std::process::id());
+        let dir = std::env::temp_dir().join(dir_name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
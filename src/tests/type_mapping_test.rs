@@ -0,0 +1,98 @@
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Order:
+      type: object
+      required: [id, createdAt]
+      properties:
+        id:
+          type: string
+          format: uuid
+        createdAt:
+          type: string
+          format: date-time
+        updatedAt:
+          type: string
+          format: date-time
+        price:
+          type: string
+        comment:
+          type: string
+        links:
+          type: array
+          items:
+            type: string
+            format: uri
+    UserId:
+      type: string
+      format: uuid
+"##;
+
+#[test]
+fn test_builtin_date_time() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::default();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"    #[serde(rename = "createdAt")]
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+"#
+    ));
+    assert!(s.contains(
+        r#"    #[serde(rename = "updatedAt")]
+    #[serde(with = "time::serde::rfc3339::option", default)]
+    pub updated_at: Option<time::OffsetDateTime>,
+"#
+    ));
+    assert!(s.contains("pub id: String,"));
+}
+
+#[test]
+fn test_type_mappings() {
+    let filter = r#"
+type_mappings:
+  - type: string
+    format: date-time
+    rust_type: chrono::DateTime<chrono::Utc>
+  - format: uuid
+    rust_type: uuid::Uuid
+  - type: string
+    format: uri
+    rust_type: url::Url
+  - schema: Order
+    field: price
+    rust_type: rust_decimal::Decimal
+    with: rust_decimal::serde::str
+    option_with: rust_decimal::serde::str_option
+  - type: string
+    rust_type: std::sync::Arc<str>
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    // the built-in mapping is replaced
+    assert!(s.contains(
+        r#"    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+"#
+    ));
+    assert!(s.contains("pub updated_at: Option<chrono::DateTime<chrono::Utc>>,"));
+    assert!(!s.contains("time::serde"));
+
+    assert!(s.contains("pub id: uuid::Uuid,"));
+    assert!(s.contains("pub links: Option<Vec<url::Url>>,"));
+    assert!(s.contains(
+        r#"    #[serde(with = "rust_decimal::serde::str_option", default)]
+    pub price: Option<rust_decimal::Decimal>,
+"#
+    ));
+    // the less specific mapping
+    assert!(s.contains("pub comment: Option<std::sync::Arc<str>>,"));
+    // type aliases
+    assert!(s.contains("pub type UserId = uuid::Uuid;"));
+}
@@ -25,7 +25,7 @@ components:
         $ref: '#/components/schemas/Pet'
 "##;
 
-    let openapi = OpenApi::from_str(SCHEMA);
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
     let config = FilterConfig::default();
     let s = generate_openapi_types(openapi, config).unwrap();
 
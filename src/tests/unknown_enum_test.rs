@@ -0,0 +1,103 @@
+use super::fixtures::{assert_generated, integer_enum_limits, round_trip};
+use crate::*;
+
+const SCHEMA: &str = r##"
+components:
+  schemas:
+    Status:
+      type: string
+      enum: [active, blocked]
+    Color:
+      type: string
+      enum: [red, unknown]
+    Priority:
+      type: integer
+      enum: [1, 2]
+      x-enum-varnames: [Low, High]
+"##;
+
+#[test]
+fn test_unknown_variant() {
+    let filter = r#"
+unknown_enum_variants: [Status, Priority]
+enum_derives: [Debug, Clone, Copy, PartialEq, Eq, Deserialize, Display]
+"#;
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains(
+        r#"#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Status {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "blocked")]
+    Blocked,
+    #[serde(untagged)]
+    Unknown(String),
+}"#
+    ));
+    assert!(s.contains("            Status::Unknown(value) => write!(f, \"{value}\"),\n"));
+
+    assert!(s.contains(
+        r#"#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low,
+    High,
+    Unknown(i64),
+}"#
+    ));
+    assert!(s.contains("            value => Ok(Priority::Unknown(value)),\n"));
+    assert!(s.contains("            Priority::Unknown(value) => write!(f, \"{value}\"),\n"));
+
+    // not listed in the filter
+    assert!(
+        s.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]\npub enum Color {")
+    );
+}
+
+#[test]
+fn test_unknown_variant_for_all() {
+    let openapi = OpenApi::from_str(SCHEMA).unwrap();
+    let config = FilterConfig::from_str("unknown_enum_variants: ['*']").unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert!(s.contains("    Unknown(String),\n}"));
+    assert!(s.contains("    Unknown(i64),\n}"));
+    // the name is already taken by an item
+    assert!(s.contains("    #[serde(rename = \"unknown\")]\n    Unknown,\n    #[serde(untagged)]\n    UnknownValue(String),\n}"));
+}
+
+const LIMITS_SCHEMA: &str = r##"
+components:
+  schemas:
+    Limit:
+      type: integer
+      format: int64
+      enum: [-9223372036854775808, 9223372036854775807]
+"##;
+
+#[test]
+fn test_unknown_variant_at_the_end_of_range() {
+    let openapi = OpenApi::from_str(LIMITS_SCHEMA).unwrap();
+    let filter = "unknown_enum_variants: ['*']\nserialize: true";
+    let config = FilterConfig::from_str(filter).unwrap();
+    let s = generate_openapi_types(openapi, config).unwrap();
+
+    assert_generated(&s, integer_enum_limits::CODE);
+    assert!(
+        s.contains("            9223372036854775807 => Ok(Limit::Value9223372036854775807),\n")
+    );
+    assert_eq!(
+        round_trip::<integer_enum_limits::Limit>("9223372036854775807"),
+        integer_enum_limits::Limit::Value9223372036854775807
+    );
+    assert_eq!(
+        round_trip::<integer_enum_limits::Limit>("-9223372036854775808"),
+        integer_enum_limits::Limit::ValueMinus9223372036854775808
+    );
+    assert_eq!(
+        round_trip::<integer_enum_limits::Limit>("5"),
+        integer_enum_limits::Limit::Unknown(5)
+    );
+}
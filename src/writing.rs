@@ -1,6 +1,7 @@
-use crate::datatypes::{DataType, FieldType};
+use crate::datatypes::{DataType, FieldType, StructField, TaggedVariant};
+use crate::filter::{DATE_SERDE_MODULE, FilterConfig, TIME_SERDE_MODULE, TypeMapping};
 use convert_case::{Case, Casing};
-use std::fmt::{Result, Write};
+use std::fmt::{Error, Result, Write};
 
 /// Writes a description of the generated module (file)
 pub fn write_comment_header<W: Write>(w: &mut W) -> Result {
@@ -16,155 +17,791 @@ pub fn write_comment_header<W: Write>(w: &mut W) -> Result {
 pub fn write_rust_code<W: Write>(
     w: &mut W,
     datatypes: &[DataType],
-    struct_derives: &[String],
-    enum_derives: &[String],
+    config: &FilterConfig,
 ) -> Result {
     let indent = "    "; // 4 * <space>
+    let serialize = config.is_serialize_enabled();
+    let struct_derives = &get_derives(&config.struct_derives, serialize);
+    let enum_derives = &get_derives(&config.enum_derives, serialize);
 
     // Necessary auxiliary types that were not present in the schema. These
     // types are "invisible" and are only needed to ensure the correctness of
     // the generated code.
     let mut helper_types = vec![];
+    // the tagged unions of the same types may differ in the tag values
+    let tagged_unions = &collect_tagged_unions(datatypes);
 
-    writeln!(w, "use serde::Deserialize;")?;
+    if serialize {
+        writeln!(w, "use serde::{{Deserialize, Serialize}};")?;
+    } else {
+        writeln!(w, "use serde::Deserialize;")?;
+    }
     writeln!(w)?; // add newline
 
     for dt in datatypes {
         match dt {
             DataType::Struct { name, fields } => {
                 // generate helper types
+                let mut with_modules = vec![];
                 for field in fields {
-                    match &field.type_ {
-                        FieldType::Plain(_) => (),
-                        FieldType::OneOf(items) => {
-                            let name = generate_union_name(&items);
-                            if !helper_types.contains(&name) {
-                                helper_types.push(name);
-                                write_union_type(w, &items, struct_derives)?;
-                            }
-                        }
-                    }
+                    write_helper_types(w, field, &mut helper_types, tagged_unions, struct_derives)?;
+                    with_modules.push(write_serde_modules(
+                        w,
+                        field,
+                        name,
+                        config,
+                        is_double_option(field, config),
+                        &mut helper_types,
+                    )?);
                 }
 
                 writeln!(w, "/// {name}")?; // keep the original name
                 writeln!(w, "#[derive({})]", struct_derives.join(", "))?;
                 writeln!(w, "pub struct {} {{", name.to_case(Case::Pascal))?;
-                for field in fields {
+                for (field, with) in fields.iter().zip(with_modules) {
                     let rust_name = fix_rust_keyword(field.translated_name.to_case(Case::Snake));
-
-                    let mut t = match &field.type_ {
-                        FieldType::Plain(t) => get_rust_type(t, &field.type_format),
-                        FieldType::OneOf(items) => generate_union_name(&items),
-                    };
-                    for _ in 0..field.array_dimensions {
-                        t = format!("Vec<{t}>");
-                    }
-                    if field.is_nullable {
-                        t = format!("Option<{t}>");
-                    }
+                    let mut t = get_field_type(field, name, tagged_unions, config);
+                    let double_option = is_double_option(field, config);
 
                     if !field.descr.is_empty() {
                         for line in field.descr.trim().lines() {
                             writeln!(w, "{indent}/// {}", line.trim())?;
                         }
                     }
-                    // if the name of the property differs according to the
-                    // naming rules of Rust
-                    if field.translated_name != rust_name || field.name != field.translated_name {
+                    if field.is_flatten {
+                        // the field has no name in the serialized object
+                        writeln!(w, "{indent}#[serde(flatten)]")?;
+                    } else if field.translated_name != rust_name
+                        || field.name != field.translated_name
+                    {
+                        // if the name of the property differs according to
+                        // the naming rules of Rust
                         writeln!(w, "{indent}#[serde(rename = {:?})]", field.name)?;
                     }
-                    // Special instructions are required for [`OffsetDateTime`]
-                    if t == "time::OffsetDateTime" {
-                        writeln!(w, "{indent}#[serde(with = \"time::serde::iso8601\")]")?;
-                    } else if t == "Option<time::OffsetDateTime>" {
+                    if serialize && !field.is_required && !field.is_flatten {
+                        // do not write `null` for missing values, but keep
+                        // the required ones, even if they are null
                         writeln!(
                             w,
-                            "{indent}#[serde(with = \"time::serde::iso8601::option\", default)]"
+                            "{indent}#[serde(skip_serializing_if = \"Option::is_none\")]"
                         )?;
                     }
+                    if double_option {
+                        // `None` if the property is absent, `Some(None)` if
+                        // it is null; the module of the type mapping does
+                        // the same by itself
+                        if with.is_none() {
+                            writeln!(
+                                w,
+                                "{indent}#[serde(default, with = \"::serde_with::rust::double_option\")]"
+                            )?;
+                        }
+                        t = format!("Option<{t}>");
+                    }
+                    if let Some(with) = with {
+                        // the type needs special (de)serialization, and
+                        // `default` makes the absent property `None`
+                        let default = if field.is_optional() { ", default" } else { "" };
+                        writeln!(w, "{indent}#[serde(with = {with:?}{default})]")?;
+                    }
                     writeln!(w, "{indent}pub {rust_name}: {t},")?;
                 }
                 writeln!(w, "}}\n")?;
             }
             DataType::Enum { name, items } => {
                 writeln!(w, "/// {name}")?; // keep the original name
+                let unknown = get_unknown_variant(config, name, items.iter().map(|i| &i.0));
                 let derives: Vec<_> = enum_derives
                     .iter()
-                    .cloned()
                     .filter(|item| *item != "Display")
+                    // the unknown value is kept in a `String`
+                    .filter(|item| unknown.is_none() || *item != "Copy")
+                    .cloned()
                     .collect();
                 writeln!(w, "#[derive({})]", derives.join(", "))?;
 
                 writeln!(w, "pub enum {} {{", name.to_case(Case::Pascal))?;
-                for item in items {
-                    let rust_name = item.to_case(Case::Pascal);
-                    // if the name of the property differs according to the
-                    // naming rules of Rust
-                    if rust_name != *item {
-                        writeln!(w, "{indent}#[serde(rename = {item:?})]")?;
+                for (item, value) in items {
+                    // if the name of the variant differs from the value
+                    if item != value {
+                        writeln!(w, "{indent}#[serde(rename = {value:?})]")?;
                     }
-                    writeln!(w, "{indent}{rust_name},")?;
+                    writeln!(w, "{indent}{item},")?;
+                }
+                if let Some(unknown) = unknown {
+                    // any other value, instead of a deserialization error
+                    writeln!(w, "{indent}#[serde(untagged)]")?;
+                    writeln!(w, "{indent}{unknown}(String),")?;
                 }
                 writeln!(w, "}}\n")?;
 
                 // write display impl if Disaply was specified in derives
                 if enum_derives.iter().any(|item| *item == "Display") {
-                    write_display_impl_for_enum(w, dt)?;
+                    write_display_impl_for_enum(w, dt, unknown)?;
                 }
             }
-            DataType::Alias { alias, info } => {
-                let mut t = match &info.type_ {
-                    FieldType::Plain(t) => get_rust_type(t, &info.type_format),
-                    FieldType::OneOf(items) => {
-                        let name = generate_union_name(&items);
-                        if !helper_types.contains(&name) {
-                            helper_types.push(name.clone());
-                            write_union_type(w, &items, struct_derives)?;
-                        }
-                        name
+            DataType::IntegerEnum { name, items } => {
+                writeln!(w, "/// {name}")?; // keep the original name
+                // serde derives the enums only as strings, so the
+                // implementations are written manually
+                let derives: Vec<_> = enum_derives
+                    .iter()
+                    .filter(|item| {
+                        !["Display", "Deserialize", "Serialize"].contains(&item.as_str())
+                    })
+                    .cloned()
+                    .collect();
+                writeln!(w, "#[derive({})]", derives.join(", "))?;
+
+                let enum_name = name.to_case(Case::Pascal);
+                let unknown = get_unknown_variant(config, name, items.iter().map(|i| &i.0));
+                // the discriminant of `Unknown` would follow the last value
+                // and could overflow, so with this variant, the values are
+                // only kept by the manual implementations
+                if unknown.is_none() {
+                    writeln!(w, "#[repr(i64)]")?;
+                }
+                writeln!(w, "pub enum {enum_name} {{")?;
+                for (item, value) in items {
+                    let item_name = item.to_case(Case::Pascal);
+                    match unknown {
+                        Some(_) => writeln!(w, "{indent}{item_name},")?,
+                        None => writeln!(w, "{indent}{item_name} = {value},")?,
                     }
-                };
+                }
+                if let Some(unknown) = unknown {
+                    writeln!(w, "{indent}{unknown}(i64),")?;
+                }
+                writeln!(w, "}}\n")?;
+
+                if enum_derives.iter().any(|item| *item == "Deserialize") {
+                    write_deserialize_impl_for_integer_enum(w, &enum_name, items, unknown)?;
+                }
+                if enum_derives.iter().any(|item| *item == "Serialize") {
+                    write_serialize_impl_for_integer_enum(w, &enum_name, items, unknown)?;
+                }
+                if enum_derives.iter().any(|item| *item == "Display") {
+                    write_display_impl_for_enum(w, dt, unknown)?;
+                }
+            }
+            DataType::Union {
+                name,
+                tag,
+                variants,
+            } => {
+                // generate helper types
+                let mut with_modules = vec![];
+                for variant in variants {
+                    write_helper_types(
+                        w,
+                        &variant.info,
+                        &mut helper_types,
+                        tagged_unions,
+                        struct_derives,
+                    )?;
+                    with_modules.push(write_serde_modules(
+                        w,
+                        &variant.info,
+                        name,
+                        config,
+                        false,
+                        &mut helper_types,
+                    )?);
+                }
 
-                for _ in 0..info.array_dimensions {
-                    t = format!("Vec<{t}>");
+                writeln!(w, "/// {name}")?; // keep the original name
+                // yes, this is an enum, but it is used only for combining
+                // structs, so derives from structs are used
+                writeln!(w, "#[derive({})]", struct_derives.join(", "))?;
+                match tag {
+                    Some(tag) => writeln!(w, "#[serde(tag = {tag:?})]")?,
+                    None => writeln!(w, "#[serde(untagged)]")?,
                 }
-                if info.is_nullable {
-                    t = format!("Option<{t}>");
+                writeln!(w, "pub enum {} {{", name.to_case(Case::Pascal))?;
+                for (variant, with) in variants.iter().zip(with_modules) {
+                    if tag.is_some() && variant.tag_value != variant.name {
+                        writeln!(w, "{indent}#[serde(rename = {:?})]", variant.tag_value)?;
+                    }
+                    let t = get_field_type(&variant.info, name, tagged_unions, config);
+                    match with {
+                        Some(with) => writeln!(
+                            w,
+                            "{indent}{}(#[serde(with = {with:?})] {t}),",
+                            variant.name
+                        )?,
+                        None => writeln!(w, "{indent}{}({t}),", variant.name)?,
+                    }
                 }
+                writeln!(w, "}}\n")?;
+            }
+            DataType::Alias { alias, info } => {
+                write_helper_types(w, info, &mut helper_types, tagged_unions, struct_derives)?;
+                let with = write_serde_modules(w, info, alias, config, false, &mut helper_types)?;
+                let t = get_field_type(info, alias, tagged_unions, config);
 
                 writeln!(w, "/// {alias}")?; // keep the original name
-                writeln!(w, "pub type {} = {t};\n", alias.to_case(Case::Pascal))?;
+                match with {
+                    // an alias cannot change the (de)serialization of the
+                    // type, so it becomes a wrapper
+                    Some(with) => {
+                        writeln!(w, "#[derive({})]", struct_derives.join(", "))?;
+                        writeln!(
+                            w,
+                            "pub struct {}(#[serde(with = {with:?})] pub {t});\n",
+                            alias.to_case(Case::Pascal)
+                        )?;
+                    }
+                    None => writeln!(w, "pub type {} = {t};\n", alias.to_case(Case::Pascal))?,
+                }
             }
         }
     }
     Ok(())
 }
 
+/// Returns `true` if the field is `Option<Option<T>>`, to distinguish the
+/// absent property from `null`
+fn is_double_option(field: &StructField, config: &FilterConfig) -> bool {
+    config.double_option && field.is_absent_or_null() && !field.is_flatten
+}
+
+/// Writes the modules for `#[serde(with = "...")]`, if the type mapping of
+/// the value requires them, and returns the path of the module. The module
+/// of the mapping handles a single value, so the modules for the arrays, the
+/// maps and the optional values are generated around it. With
+/// `double_option`, the value is `Option<Option<T>>`, see
+/// [`is_double_option`].
+fn write_serde_modules<W: Write>(
+    w: &mut W,
+    field: &StructField,
+    schema_name: &str,
+    config: &FilterConfig,
+    double_option: bool,
+    helper_types: &mut Vec<String>,
+) -> std::result::Result<Option<String>, Error> {
+    let Some((mapping, shape)) = find_nested_type_mapping(field, schema_name, config) else {
+        return Ok(None);
+    };
+    let Some(with) = mapping.with else {
+        return Ok(None);
+    };
+    if with == TIME_SERDE_MODULE && !helper_types.contains(&with) {
+        helper_types.push(with.clone());
+        write_time_serde_module(w)?;
+    }
+    if with == DATE_SERDE_MODULE && !helper_types.contains(&with) {
+        helper_types.push(with.clone());
+        write_date_serde_module(w)?;
+    }
+
+    if !double_option {
+        if shape.is_empty() {
+            return Ok(Some(with));
+        }
+        if shape == ["option"]
+            && let Some(option_with) = mapping.option_with
+        {
+            return Ok(Some(option_with));
+        }
+    }
+
+    // the path can contain generics, like `serde_with::As::<Base64>`
+    let path: Vec<_> = with
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect();
+    let double = if double_option { "double_" } else { "" };
+    let module = format!("_{}_{double}{}", path.join("_"), shape.join("_")).to_lowercase();
+    if !helper_types.contains(&module) {
+        helper_types.push(module.clone());
+        write_serde_container_module(w, &module, &with, &mapping.rust_type, &shape, double_option)?;
+    }
+    Ok(Some(module))
+}
+
+/// Returns the type mapping of the value and the containers around it, from
+/// the outer one: `"option"`, `"vec"` or `"map"`
+fn find_nested_type_mapping(
+    field: &StructField,
+    schema_name: &str,
+    config: &FilterConfig,
+) -> Option<(TypeMapping, Vec<&'static str>)> {
+    let mut shape = vec![];
+    if field.is_optional() && !field.is_flatten {
+        shape.push("option");
+    }
+    shape.extend(std::iter::repeat_n("vec", field.array_dimensions as usize));
+    match &field.type_ {
+        FieldType::Plain(_) if !field.is_flatten => {
+            let mapping = find_type_mapping(field, schema_name, config)?;
+            Some((mapping, shape))
+        }
+        // the values of `additionalProperties` are mapped as well
+        FieldType::Map(value) => {
+            let (mapping, value_shape) = find_nested_type_mapping(value, schema_name, config)?;
+            shape.push("map");
+            shape.extend(value_shape);
+            Some((mapping, shape))
+        }
+        _ => None,
+    }
+}
+
+/// Wraps the type into the containers, the outer one is the first
+fn wrap_into_containers(t: &str, shape: &[&str]) -> String {
+    let mut t = t.to_owned();
+    for container in shape.iter().rev() {
+        t = match *container {
+            "option" => format!("Option<{t}>"),
+            "vec" => format!("Vec<{t}>"),
+            _ => format!("std::collections::HashMap<String, {t}>"),
+        };
+    }
+    t
+}
+
+/// Returns the expression that turns the reference to the container `var`
+/// into the container of the `Ser` wrappers
+fn to_wrappers(var: &str, shape: &[&str]) -> String {
+    let Some((container, inner)) = shape.split_first() else {
+        return format!("Ser({var})");
+    };
+    let f = match inner {
+        [] => "Ser".to_owned(),
+        _ => format!("|v| {}", to_wrappers("v", inner)),
+    };
+    match *container {
+        "option" => format!("{var}.as_ref().map({f})"),
+        "vec" => format!("{var}.iter().map({f}).collect::<Vec<_>>()"),
+        _ => format!(
+            "{var}.iter().map(|(k, v)| (k, {})).collect::<std::collections::HashMap<_, _>>()",
+            to_wrappers("v", inner)
+        ),
+    }
+}
+
+/// Returns the expression that turns the container of the `De` wrappers
+/// `var` into the container of the values
+fn from_wrappers(var: &str, shape: &[&str]) -> String {
+    let Some((container, inner)) = shape.split_first() else {
+        return format!("{var}.0");
+    };
+    let f = match inner {
+        [] => "|v| v.0".to_owned(),
+        _ => format!("|v| {}", from_wrappers("v", inner)),
+    };
+    match *container {
+        "option" => format!("{var}.map({f})"),
+        "vec" => format!("{var}.into_iter().map({f}).collect()"),
+        _ => format!(
+            "{var}.into_iter().map(|(k, v)| (k, {})).collect()",
+            from_wrappers("v", inner)
+        ),
+    }
+}
+
+/// Writes a module for (de)serialization of the containers (`shape`) of
+/// the values, which are (de)serialized by the module `with`. With
+/// `double_option`, the container is wrapped into one more `Option`, which
+/// is `None` only if the property is absent.
+fn write_serde_container_module<W: Write>(
+    w: &mut W,
+    module: &str,
+    with: &str,
+    rust_type: &str,
+    shape: &[&str],
+    double_option: bool,
+) -> Result {
+    let indent1 = " ".repeat(4);
+    let indent2 = " ".repeat(8);
+    let indent3 = " ".repeat(12);
+    // the generated modules are declared next to this one
+    let with = if with.starts_with('_') {
+        format!("super::{with}")
+    } else {
+        with.to_owned()
+    };
+    let mut container = wrap_into_containers(rust_type, shape);
+    let mut to_wrappers = to_wrappers("value", shape);
+    let mut from_wrappers = from_wrappers("value", shape);
+    if double_option {
+        // the property is present, so it is `Some`, even if it is `null`
+        container = format!("Option<{container}>");
+        to_wrappers = format!("value.as_ref().map(|value| {to_wrappers})");
+        from_wrappers = format!("Some({from_wrappers})");
+    }
+
+    writeln!(w, "/// (De)serialization of `{container}` by `{with}`")?;
+    writeln!(w, "mod {module} {{")?;
+    writeln!(w, "{indent1}struct Ser<'a>(&'a {rust_type});")?;
+    writeln!(w)?;
+    writeln!(w, "{indent1}impl serde::Serialize for Ser<'_> {{")?;
+    writeln!(
+        w,
+        "{indent2}fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "{indent3}{with}::serialize(self.0, serializer)")?;
+    writeln!(w, "{indent2}}}")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w)?;
+    writeln!(w, "{indent1}struct De({rust_type});")?;
+    writeln!(w)?;
+    writeln!(w, "{indent1}impl<'de> serde::Deserialize<'de> for De {{")?;
+    writeln!(
+        w,
+        "{indent2}fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(w, "{indent3}{with}::deserialize(deserializer).map(De)")?;
+    writeln!(w, "{indent2}}}")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn serialize<S: serde::Serializer>(value: &{container}, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}serde::Serialize::serialize(&{to_wrappers}, serializer)"
+    )?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<{container}, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}let value: {} = serde::Deserialize::deserialize(deserializer)?;",
+        wrap_into_containers("De", shape)
+    )?;
+    writeln!(w, "{indent2}Ok({from_wrappers})")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+/// Writes a module for (de)serialization of `time::Time`. By itself, `time`
+/// reads only the values with fractions of a second, like "17:32:28.0", and
+/// its ISO 8601 format accepts an offset, which `time::Time` cannot keep.
+fn write_time_serde_module<W: Write>(w: &mut W) -> Result {
+    let indent1 = " ".repeat(4);
+    let indent2 = " ".repeat(8);
+    let indent3 = " ".repeat(12);
+    writeln!(
+        w,
+        "/// (De)serialization of `time::Time` in RFC 3339 without an offset, \"17:32:28\""
+    )?;
+    writeln!(w, "/// or \"17:32:28.5\"")?;
+    writeln!(w, "mod {TIME_SERDE_MODULE} {{")?;
+    writeln!(w, "{indent1}use time::macros::format_description;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn serialize<S: serde::Serializer>(value: &time::Time, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "{indent2}let value = if value.nanosecond() == 0 {{")?;
+    writeln!(
+        w,
+        "{indent3}value.format(format_description!(\"[hour]:[minute]:[second]\"))"
+    )?;
+    writeln!(w, "{indent2}}} else {{")?;
+    writeln!(
+        w,
+        "{indent3}value.format(format_description!(\"[hour]:[minute]:[second].[subsecond]\"))"
+    )?;
+    writeln!(w, "{indent2}}};")?;
+    writeln!(
+        w,
+        "{indent2}serializer.serialize_str(&value.map_err(serde::ser::Error::custom)?)"
+    )?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<time::Time, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}let value: String = serde::Deserialize::deserialize(deserializer)?;"
+    )?;
+    writeln!(
+        w,
+        "{indent2}let format = format_description!(\"[hour]:[minute]:[second][optional [.[subsecond]]]\");"
+    )?;
+    writeln!(
+        w,
+        "{indent2}time::Time::parse(&value, format).map_err(serde::de::Error::custom)"
+    )?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+/// Writes a module for (de)serialization of `time::Date` in RFC 3339, like
+/// "2017-07-21". `time` has no well-known format for the dates alone.
+fn write_date_serde_module<W: Write>(w: &mut W) -> Result {
+    let indent1 = " ".repeat(4);
+    let indent2 = " ".repeat(8);
+    writeln!(
+        w,
+        "/// (De)serialization of `time::Date` in RFC 3339, \"2017-07-21\""
+    )?;
+    writeln!(w, "mod {DATE_SERDE_MODULE} {{")?;
+    writeln!(w, "{indent1}use time::macros::format_description;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn serialize<S: serde::Serializer>(value: &time::Date, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}let value = value.format(format_description!(\"[year]-[month]-[day]\"));"
+    )?;
+    writeln!(
+        w,
+        "{indent2}serializer.serialize_str(&value.map_err(serde::ser::Error::custom)?)"
+    )?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{indent1}pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<time::Date, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}let value: String = serde::Deserialize::deserialize(deserializer)?;"
+    )?;
+    writeln!(
+        w,
+        "{indent2}time::Date::parse(&value, format_description!(\"[year]-[month]-[day]\")).map_err(serde::de::Error::custom)"
+    )?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+/// Returns the mapping of the field type to the Rust type, if it is
+/// configured
+fn find_type_mapping(
+    field: &StructField,
+    schema_name: &str,
+    config: &FilterConfig,
+) -> Option<TypeMapping> {
+    match &field.type_ {
+        FieldType::Plain(t) => {
+            config.find_type_mapping(schema_name, &field.name, t, &field.type_format)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the name of the variant for the values that are not listed in the
+/// specification, if it is enabled for the enumeration
+fn get_unknown_variant<'a>(
+    config: &FilterConfig,
+    name: &str,
+    mut items: impl Iterator<Item = &'a String>,
+) -> Option<&'static str> {
+    if !config.has_unknown_variant(name) {
+        return None;
+    }
+    // the name must differ from the listed items
+    if items.any(|item| item.to_case(Case::Pascal) == "Unknown") {
+        Some("UnknownValue")
+    } else {
+        Some("Unknown")
+    }
+}
+
+/// Returns the derives, adding `Serialize` to them if necessary
+fn get_derives(derives: &[String], serialize: bool) -> Vec<String> {
+    let mut derives = derives.to_vec();
+    if serialize && !derives.iter().any(|d| d == "Serialize") {
+        derives.push("Serialize".into());
+    }
+    derives
+}
+
+/// Writes the auxiliary types that the field needs, if they have not been
+/// written yet
+fn write_helper_types<W: Write>(
+    w: &mut W,
+    field: &StructField,
+    helper_types: &mut Vec<String>,
+    tagged_unions: &[TaggedUnion],
+    struct_derives: &[String],
+) -> Result {
+    match &field.type_ {
+        FieldType::Plain(_) => Ok(()),
+        FieldType::OneOf(items) => {
+            let name = generate_union_name(items);
+            if !helper_types.contains(&name) {
+                helper_types.push(name);
+                write_union_type(w, items, struct_derives)?;
+            }
+            Ok(())
+        }
+        FieldType::Tagged { property, variants } => {
+            let name = generate_tagged_union_name(property, variants, tagged_unions);
+            if !helper_types.contains(&name) {
+                helper_types.push(name.clone());
+                write_tagged_union_type(w, &name, property, variants, struct_derives)?;
+            }
+            Ok(())
+        }
+        FieldType::Map(value) => {
+            write_helper_types(w, value, helper_types, tagged_unions, struct_derives)
+        }
+    }
+}
+
+/// Returns the Rust type of the field, including arrays and nullability
+fn get_field_type(
+    field: &StructField,
+    schema_name: &str,
+    tagged_unions: &[TaggedUnion],
+    config: &FilterConfig,
+) -> String {
+    let mut t = match &field.type_ {
+        FieldType::Plain(t) => match find_type_mapping(field, schema_name, config) {
+            Some(mapping) => mapping.rust_type,
+            None => get_rust_type(t, &field.type_format),
+        },
+        FieldType::OneOf(items) => generate_union_name(items),
+        FieldType::Tagged { property, variants } => {
+            generate_tagged_union_name(property, variants, tagged_unions)
+        }
+        FieldType::Map(value) => {
+            format!(
+                "std::collections::HashMap<String, {}>",
+                get_field_type(value, schema_name, tagged_unions, config)
+            )
+        }
+    };
+    if field.is_boxed {
+        t = format!("std::boxed::Box<{t}>");
+    }
+    for _ in 0..field.array_dimensions {
+        t = format!("Vec<{t}>");
+    }
+    if field.is_optional() {
+        t = format!("Option<{t}>");
+    }
+    t
+}
+
 /// Writes a [`Display`](std::fmt::Display) implementation for enum
-fn write_display_impl_for_enum<W: Write>(w: &mut W, dt: &DataType) -> Result {
+fn write_display_impl_for_enum<W: Write>(
+    w: &mut W,
+    dt: &DataType,
+    unknown: Option<&str>,
+) -> Result {
     let indent1 = " ".repeat(4);
     let indent2 = " ".repeat(8);
     let indent3 = " ".repeat(12);
-    if let DataType::Enum { name, items } = dt {
-        let enum_name = name.to_case(Case::Pascal);
-        writeln!(w, "impl std::fmt::Display for {enum_name} {{")?;
+    // the names of the items and their original values
+    let (name, items): (_, Vec<_>) = match dt {
+        DataType::Enum { name, items } => {
+            (name, items.iter().map(|(i, v)| (i, v.clone())).collect())
+        }
+        DataType::IntegerEnum { name, items } => (
+            name,
+            items.iter().map(|(i, v)| (i, v.to_string())).collect(),
+        ),
+        _ => return Ok(()),
+    };
+    let enum_name = name.to_case(Case::Pascal);
+    writeln!(w, "impl std::fmt::Display for {enum_name} {{")?;
+    writeln!(
+        w,
+        "{indent1}fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+    )?;
+    writeln!(w, "{indent2}match self {{")?;
+    for (item, value) in items {
+        let item_name = item.to_case(Case::Pascal);
+        // writes a non-distorted name
         writeln!(
             w,
-            "{indent1}fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{"
+            "{indent3}{enum_name}::{item_name} => write!(f, \"{value}\"),"
         )?;
-        writeln!(w, "{indent2}match self {{")?;
-        for item in items {
-            let item_name = item.to_case(Case::Pascal);
-            // writes a non-distorted name
-            writeln!(
-                w,
-                "{indent3}{enum_name}::{item_name} => write!(f, \"{item}\"),"
-            )?;
-        }
-        writeln!(w, "{indent2}}}")?;
-        writeln!(w, "{indent1}}}")?;
-        writeln!(w, "}}\n")?;
     }
+    if let Some(unknown) = unknown {
+        writeln!(
+            w,
+            "{indent3}{enum_name}::{unknown}(value) => write!(f, \"{{value}}\"),"
+        )?;
+    }
+    writeln!(w, "{indent2}}}")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+/// Writes a `Deserialize` implementation for enum of integers, which reads
+/// the item by its value
+fn write_deserialize_impl_for_integer_enum<W: Write>(
+    w: &mut W,
+    enum_name: &str,
+    items: &[(String, i64)],
+    unknown: Option<&str>,
+) -> Result {
+    let indent1 = " ".repeat(4);
+    let indent2 = " ".repeat(8);
+    let indent3 = " ".repeat(12);
+    writeln!(w, "impl<'de> serde::Deserialize<'de> for {enum_name} {{")?;
+    writeln!(
+        w,
+        "{indent1}fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+    )?;
+    writeln!(
+        w,
+        "{indent2}match <i64 as serde::Deserialize>::deserialize(deserializer)? {{"
+    )?;
+    for (item, value) in items {
+        let item_name = item.to_case(Case::Pascal);
+        writeln!(w, "{indent3}{value} => Ok({enum_name}::{item_name}),")?;
+    }
+    match unknown {
+        Some(unknown) => writeln!(w, "{indent3}value => Ok({enum_name}::{unknown}(value)),")?,
+        None => writeln!(
+            w,
+            "{indent3}value => Err(serde::de::Error::custom(format!(\"unknown {enum_name} value: {{value}}\"))),"
+        )?,
+    }
+    writeln!(w, "{indent2}}}")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+/// Writes a `Serialize` implementation for enum of integers, which writes
+/// the value of the item
+fn write_serialize_impl_for_integer_enum<W: Write>(
+    w: &mut W,
+    enum_name: &str,
+    items: &[(String, i64)],
+    unknown: Option<&str>,
+) -> Result {
+    let indent1 = " ".repeat(4);
+    let indent2 = " ".repeat(8);
+    let indent3 = " ".repeat(12);
+    writeln!(w, "impl serde::Serialize for {enum_name} {{")?;
+    writeln!(
+        w,
+        "{indent1}fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+    )?;
+    writeln!(w, "{indent2}let value = match self {{")?;
+    for (item, value) in items {
+        let item_name = item.to_case(Case::Pascal);
+        writeln!(w, "{indent3}{enum_name}::{item_name} => {value},")?;
+    }
+    if let Some(unknown) = unknown {
+        writeln!(w, "{indent3}{enum_name}::{unknown}(value) => *value,")?;
+    }
+    writeln!(w, "{indent2}}};")?;
+    writeln!(w, "{indent2}serializer.serialize_i64(value)")?;
+    writeln!(w, "{indent1}}}")?;
+    writeln!(w, "}}\n")?;
     Ok(())
 }
 
@@ -177,13 +814,8 @@ fn get_rust_type(typename: &str, format: &str) -> String {
             _ => "f64".to_owned(),
         },
         "boolean" => "bool".to_owned(),
-        "string" => match format {
-            // the expected value is in RFC 3339/ISO 8601, "2017-07-21"
-            "date" => "time::Date".to_owned(),
-            // the expected value is in RFC 3339/ISO 8601, "2017-07-21T17:32:28Z"
-            "date-time" => "time::OffsetDateTime".to_owned(),
-            _ => "String".to_owned(),
-        },
+        // the formats like `date-time` are in the type mappings
+        "string" => "String".to_owned(),
         "integer" => match format {
             "int32" => "i32".to_owned(),
             "int64" => "i64".to_owned(),
@@ -198,7 +830,8 @@ fn get_rust_type(typename: &str, format: &str) -> String {
 /// Generates a name for the auxiliary structure, for example,
 /// `UnionNumberOrString`.
 fn generate_union_name(one_of: &[String]) -> String {
-    format!("_Union{}", one_of.join("Or").to_case(Case::Pascal))
+    let names: Vec<_> = one_of.iter().map(|t| t.to_case(Case::Pascal)).collect();
+    format!("_Union{}", names.join("Or"))
 }
 
 /// Writes an "invisible" auxiliary structure
@@ -212,7 +845,106 @@ fn write_union_type<W: Write>(w: &mut W, one_of: &[String], struct_derives: &[St
     writeln!(w, "pub enum {} {{", generate_union_name(one_of))?;
     for t in one_of {
         let name = t.to_case(Case::Pascal);
-        writeln!(w, "{indent}{name}({name}),")?;
+        // primitive types are also allowed, like `{"type": ["integer",
+        // "string"]}`
+        writeln!(w, "{indent}{name}({}),", get_rust_type(t, ""))?;
+    }
+    writeln!(w, "}}\n")
+}
+
+/// The property and the variants of a tagged union
+type TaggedUnion<'a> = (&'a str, &'a [TaggedVariant]);
+
+/// Lists all the distinct tagged unions of the fields in the order of their
+/// appearance
+fn collect_tagged_unions(datatypes: &[DataType]) -> Vec<TaggedUnion<'_>> {
+    fn collect<'a>(field_type: &'a FieldType, tagged_unions: &mut Vec<TaggedUnion<'a>>) {
+        match field_type {
+            FieldType::Plain(_) | FieldType::OneOf(_) => (),
+            FieldType::Tagged { property, variants } => {
+                let tagged_union = (property.as_str(), variants.as_slice());
+                if !tagged_unions.contains(&tagged_union) {
+                    tagged_unions.push(tagged_union);
+                }
+            }
+            FieldType::Map(value) => collect(&value.type_, tagged_unions),
+        }
+    }
+
+    let mut tagged_unions = vec![];
+    for dt in datatypes {
+        match dt {
+            DataType::Struct { fields, .. } => {
+                for field in fields {
+                    collect(&field.type_, &mut tagged_unions);
+                }
+            }
+            DataType::Union { variants, .. } => {
+                for variant in variants {
+                    collect(&variant.info.type_, &mut tagged_unions);
+                }
+            }
+            DataType::Alias { info, .. } => collect(&info.type_, &mut tagged_unions),
+            DataType::Enum { .. } | DataType::IntegerEnum { .. } => (),
+        }
+    }
+    tagged_unions
+}
+
+/// Generates a name for the auxiliary structure with a tag, for example,
+/// `UnionCatOrDogByPetType`. The unions of the same types with different
+/// values of the property get a number, like `UnionCatOrDogByPetType2`, in
+/// the order of `tagged_unions`.
+fn generate_tagged_union_name(
+    property: &str,
+    variants: &[TaggedVariant],
+    tagged_unions: &[TaggedUnion],
+) -> String {
+    let base_name = |variants: &[TaggedVariant]| {
+        let names: Vec<_> = variants.iter().map(|v| v.name.clone()).collect();
+        format!(
+            "{}By{}",
+            generate_union_name(&names),
+            property.to_case(Case::Pascal)
+        )
+    };
+    let name = base_name(variants);
+    let index = tagged_unions
+        .iter()
+        .filter(|(p, v)| *p == property && base_name(v) == name)
+        .position(|(_, v)| *v == variants)
+        .unwrap_or(0);
+    match index {
+        0 => name,
+        _ => format!("{name}{}", index + 1),
+    }
+}
+
+/// Writes an "invisible" auxiliary structure named `name`, where the variant
+/// is determined by the value of the `property`
+fn write_tagged_union_type<W: Write>(
+    w: &mut W,
+    name: &str,
+    property: &str,
+    variants: &[TaggedVariant],
+    struct_derives: &[String],
+) -> Result {
+    let indent = "    "; // 4 * <space>
+
+    writeln!(w, "#[derive({})]", struct_derives.join(", "))?;
+    writeln!(w, "#[serde(tag = {property:?})]")?;
+    writeln!(w, "pub enum {name} {{")?;
+    for variant in variants {
+        let name = variant.name.to_case(Case::Pascal);
+        let value = &variant.tag_value;
+        if *value != name {
+            writeln!(w, "{indent}#[serde(rename = {value:?})]")?;
+        }
+        writeln!(
+            w,
+            "{indent}{name}({}),",
+            variant.type_name.to_case(Case::Pascal)
+        )?;
     }
     writeln!(w, "}}\n")
 }